@@ -0,0 +1,103 @@
+//! Allocation of new codes under a GS1 company prefix, by counting up
+//! through its item references.
+
+use crate::{validate_digits, write_number, Standard, Upc, UpcError};
+
+/// Iterator yielding consecutive codes under a GS1 company prefix, created
+/// by [CodeAllocator::new]
+///
+/// Company prefixes starting with 0 (UPC company prefixes in their GTIN-13
+/// form) give [Standard::UpcA] codes, whilst any other company prefix gives
+/// [Standard::Ean13] codes. Iteration stops once every item reference has
+/// been used, where [CodeAllocator::next_upc] instead fails with
+/// [UpcError::CapacityExhausted].
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::CodeAllocator;
+///
+/// let mut allocator = CodeAllocator::new(&[0, 6, 1, 4, 1, 4, 1], 3).unwrap();
+///
+/// assert_eq!("614141000036", allocator.next_upc().unwrap().to_string());
+/// assert_eq!("614141000043", allocator.next_upc().unwrap().to_string());
+/// assert_eq!(99_995, allocator.remaining());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CodeAllocator {
+    gtin: [i8; 12],
+    prefix_len: usize,
+    next_item: u32,
+    capacity: u32,
+}
+
+impl CodeAllocator {
+    /// Creates an allocator for a company prefix in its GTIN-13 form (4 to
+    /// 12 digits), starting from the given item reference.
+    ///
+    /// Fails with [UpcError::InvalidPrefixLength] for a company prefix
+    /// outside of 4 to 12 digits, or [UpcError::ValueTooLarge] if the item
+    /// reference doesn't fit in the digits left after the company prefix.
+    pub fn new(company_prefix: &[i8], start: u32) -> Result<Self, UpcError> {
+        let prefix_len = company_prefix.len();
+        if !(4..=12).contains(&prefix_len) {
+            return Err(UpcError::InvalidPrefixLength(prefix_len));
+        }
+
+        validate_digits(company_prefix)?;
+
+        let mut gtin = [0; 12];
+        gtin[..prefix_len].copy_from_slice(company_prefix);
+        write_number(&mut gtin[prefix_len..], start.into())?;
+
+        Ok(Self {
+            gtin,
+            prefix_len,
+            next_item: start,
+            capacity: 10u32.pow((12 - prefix_len) as u32),
+        })
+    }
+
+    /// Gets how many item references are left to be allocated.
+    pub fn remaining(&self) -> u32 {
+        self.capacity - self.next_item
+    }
+
+    /// Allocates the code for the next item reference, computing its check
+    /// digit. Fails with [UpcError::CapacityExhausted] once every item
+    /// reference under the company prefix has been used.
+    pub fn next_upc(&mut self) -> Result<Upc, UpcError> {
+        if self.next_item >= self.capacity {
+            return Err(UpcError::CapacityExhausted);
+        }
+
+        write_number(&mut self.gtin[self.prefix_len..], self.next_item.into())?;
+        self.next_item += 1;
+
+        let upc = match self.gtin[0] {
+            0 => {
+                let mut upc = [0; 11];
+                upc.copy_from_slice(&self.gtin[1..]);
+
+                Standard::UpcA(upc)
+            }
+            _ => Standard::Ean13(self.gtin),
+        };
+
+        Upc::new(upc)
+    }
+}
+
+impl Iterator for CodeAllocator {
+    type Item = Upc;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_upc().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining() as usize;
+
+        (remaining, Some(remaining))
+    }
+}
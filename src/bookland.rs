@@ -0,0 +1,124 @@
+//! Interpretation of the suggested retail price carried by the EAN-5 add-on
+//! of a Bookland (ISBN-13) barcode.
+
+use crate::{Barcode, Isbn13, Supplement, UpcError};
+use core::convert::TryFrom;
+
+/// Currency of a [BooklandPrice], selected by the first digit of the add-on
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Currency {
+    /// British pound, for a first digit of 0 or 1
+    Gbp,
+
+    /// Australian dollar, for a first digit of 3
+    Aud,
+
+    /// New Zealand dollar, for a first digit of 4
+    Nzd,
+
+    /// US dollar, for a first digit of 5
+    Usd,
+
+    /// Canadian dollar, for a first digit of 6
+    Cad,
+}
+
+/// Suggested retail price decoded from a Bookland EAN-5 add-on by
+/// [Barcode::bookland_price]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum BooklandPrice {
+    /// Price in the minor units of the currency (pence or cents), such as
+    /// `1299` for an add-on of `51299`
+    Price {
+        /// Currency of the price
+        currency: Currency,
+
+        /// Price in pence or cents
+        amount: u32,
+    },
+
+    /// `90000`, meaning there is no suggested retail price
+    NoSuggestedPrice,
+
+    /// `99990`, marking a used book
+    Used,
+
+    /// `99991`, marking a complimentary copy
+    Complimentary,
+
+    /// Any other `9xxxx` code, reserved for internal use by publishers
+    Internal(u32),
+
+    /// Code with a first digit which isn't assigned to a currency
+    Unassigned(u32),
+}
+
+impl BooklandPrice {
+    /// Interprets the digits of an EAN-5 add-on as a Bookland price.
+    fn from_digits(digits: &[i8; 5]) -> Self {
+        let value = digits
+            .iter()
+            .fold(0, |value, digit| value * 10 + *digit as u32);
+        let amount = value % 10_000;
+
+        let currency = match digits[0] {
+            0 | 1 => {
+                return BooklandPrice::Price {
+                    currency: Currency::Gbp,
+                    amount: value,
+                }
+            }
+            3 => Currency::Aud,
+            4 => Currency::Nzd,
+            5 => Currency::Usd,
+            6 => Currency::Cad,
+            9 => {
+                return match value {
+                    90_000 => BooklandPrice::NoSuggestedPrice,
+                    99_990 => BooklandPrice::Used,
+                    99_991 => BooklandPrice::Complimentary,
+                    _ => BooklandPrice::Internal(value),
+                }
+            }
+            _ => return BooklandPrice::Unassigned(value),
+        };
+
+        BooklandPrice::Price { currency, amount }
+    }
+}
+
+impl Barcode {
+    /// Decodes the suggested retail price from the EAN-5 add-on of a Bookland
+    /// barcode, giving `None` if there is no EAN-5 add-on.
+    ///
+    /// Fails with [UpcError::InvalidPrefix] if the main code isn't an ISBN-13
+    /// (978 or 979), as only Bookland add-ons carry prices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::{Barcode, BooklandPrice, Currency};
+    ///
+    /// let barcode: Barcode = "9780306406157 51299".parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     Ok(Some(BooklandPrice::Price {
+    ///         currency: Currency::Usd,
+    ///         amount: 1299
+    ///     })),
+    ///     barcode.bookland_price()
+    /// );
+    /// ```
+    pub fn bookland_price(&self) -> Result<Option<BooklandPrice>, UpcError> {
+        Isbn13::try_from(self.upc.clone())?;
+
+        match &self.supplement {
+            Some(supplement @ Supplement::Ean5(digits)) => {
+                supplement.check_value()?;
+
+                Ok(Some(BooklandPrice::from_digits(digits)))
+            }
+            _ => Ok(None),
+        }
+    }
+}
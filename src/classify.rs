@@ -0,0 +1,124 @@
+//! Classification of codes by their number system or GS1 prefix.
+
+use crate::gs1_prefixes::country;
+use crate::{Standard, Upc, UpcError};
+
+/// Kind of code given by its number system (UPC) or GS1 prefix (EAN), from
+/// [Upc::classify]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Classification {
+    /// Regular trade item, issued by a GS1 member organization
+    Regular,
+
+    /// Variable-measure item with an embedded price or weight, being UPC-A
+    /// number system 2 or EAN-13 prefixes 20-29
+    VariableMeasure,
+
+    /// Restricted for use within a store or company, being UPC-A number
+    /// system 4 or an EAN-8 starting with 0 or 2
+    RestrictedInStore,
+
+    /// Drug or health item carrying a National Drug Code, being UPC-A number
+    /// system 3
+    Drug,
+
+    /// Coupon, being UPC-A number system 5 or EAN-13 prefixes 981-984 and
+    /// 99
+    Coupon,
+
+    /// Book (ISBN) or printed music (ISMN), being EAN-13 prefixes 978-979
+    Bookland,
+
+    /// Serial publication (ISSN), being EAN-13 prefix 977
+    Serial,
+
+    /// Refund receipt, being EAN-13 prefix 980
+    RefundReceipt,
+
+    /// Prefix which isn't assigned
+    Unassigned,
+}
+
+impl Upc {
+    /// Classifies this code by its leading digits, being the number system of
+    /// a UPC or the GS1 prefix of an EAN.
+    ///
+    /// UPC-A and UPC-E codes are classified by their GTIN-13 form, so their
+    /// number system becomes the second digit of the prefix, and a GTIN-14
+    /// ignores its packaging indicator. Fails with [UpcError::UpcOverflow] if
+    /// any digit isn't 0-9, as with [Upc::check].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::{Classification, Upc};
+    ///
+    /// let code: Upc = "512345678016".parse().unwrap();
+    ///
+    /// assert_eq!(Ok(Classification::Coupon), code.classify());
+    /// ```
+    pub fn classify(&self) -> Result<Classification, UpcError> {
+        self.validate_upc_overflow()?;
+
+        if let Standard::Ean8(x) = &self.upc {
+            if x[0] == 0 || x[0] == 2 {
+                return Ok(Classification::RestrictedInStore);
+            }
+        }
+
+        let classification = match self.gs1_prefix() {
+            20..=29 | 200..=299 => Classification::VariableMeasure,
+            30..=39 => Classification::Drug,
+            40..=49 => Classification::RestrictedInStore,
+            50..=59 | 981..=984 | 990..=999 => Classification::Coupon,
+            977 => Classification::Serial,
+            978..=979 => Classification::Bookland,
+            980 => Classification::RefundReceipt,
+            prefix if country(prefix).is_some() => Classification::Regular,
+            _ => Classification::Unassigned,
+        };
+
+        Ok(classification)
+    }
+
+    /// Gets the country of the GS1 member organization which issued this
+    /// code's company prefix, or `None` for restricted and special prefixes
+    /// such as variable-measure codes and Bookland. Fails in the same cases as
+    /// [Upc::classify].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::Upc;
+    ///
+    /// let code: Upc = "4006381333931".parse().unwrap();
+    ///
+    /// assert_eq!(Ok(Some("Germany")), code.member_organization());
+    /// ```
+    pub fn member_organization(&self) -> Result<Option<&'static str>, UpcError> {
+        let country = match self.classify()? {
+            Classification::Regular | Classification::Drug | Classification::Coupon => {
+                country(self.gs1_prefix())
+            }
+            _ => None,
+        };
+
+        Ok(country)
+    }
+
+    /// Gets the first 3 digits of this code's (already validated) GTIN-13
+    /// form, or of the EAN-8 itself.
+    fn gs1_prefix(&self) -> u16 {
+        let digits = match &self.upc {
+            Standard::Ean8(x) => [x[0], x[1], x[2]],
+            _ => {
+                let gtin = self.gtin14_digits();
+                [gtin[1], gtin[2], gtin[3]]
+            }
+        };
+
+        digits
+            .iter()
+            .fold(0, |prefix, digit| prefix * 10 + *digit as u16)
+    }
+}
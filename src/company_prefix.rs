@@ -0,0 +1,183 @@
+//! Splitting GTINs into their GS1 company prefix and item reference.
+
+use crate::{Standard, Upc, UpcError};
+use core::fmt;
+
+/// Length of the GS1 company prefixes starting with `prefix`, written as the
+/// leading digits of a GTIN-13, where a length of 0 means codes starting
+/// with `prefix` don't carry a company prefix
+struct PrefixLength {
+    prefix: &'static str,
+    length: usize,
+}
+
+/// Shorthand for writing a [PrefixLength]
+const fn prefix_length(prefix: &'static str, length: usize) -> PrefixLength {
+    PrefixLength { prefix, length }
+}
+
+/// Company prefix lengths compiled from GS1's
+/// [GCP length list](https://www.gs1.org/standards/bc-epc-interop), where
+/// the longest matching prefix wins.
+///
+/// The table is generated by running `tools/gcp_lengths.py` on a
+/// `gcpprefixformatlist.xml`, which rewrites everything between the
+/// `BEGIN GENERATED TABLE` and `END GENERATED TABLE` markers, and should be
+/// regenerated rather than edited by hand. Codes under a prefix missing from
+/// the table give [UpcError::UnknownCompanyPrefix] rather than a guessed
+/// length, so the length has to be passed to [Upc::gtin_parts] instead.
+// BEGIN GENERATED TABLE
+// Not generated from gcpprefixformatlist.xml yet, so only the prefixes which
+// never carry a company prefix are listed and every other lookup fails; run
+// tools/gcp_lengths.py on a current gcpprefixformatlist.xml to fill it in.
+static PREFIX_LENGTHS: &[PrefixLength] = &[
+    // variable measure, in-store and coupon numbers
+    prefix_length("02", 0),
+    prefix_length("04", 0),
+    prefix_length("05", 0),
+    // restricted circulation numbers
+    prefix_length("2", 0),
+    // refund receipt and coupon numbers
+    prefix_length("98", 0),
+    prefix_length("99", 0),
+];
+// END GENERATED TABLE
+
+/// GTIN split into its packaging indicator, GS1 company prefix, item
+/// reference and check digit, from [Upc::gtin_parts] or
+/// [Upc::lookup_gtin_parts]
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::Upc;
+///
+/// let code: Upc = "614141000036".parse().unwrap();
+/// let parts = code.gtin_parts(7).unwrap();
+///
+/// assert_eq!(&[0, 6, 1, 4, 1, 4, 1], parts.company_prefix());
+/// assert_eq!(&[0, 0, 0, 0, 3], parts.item_reference());
+/// assert_eq!("0-0614141-00003-6", parts.to_string());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct GtinParts {
+    gtin: [i8; 13],
+    check_digit: i8,
+    prefix_len: usize,
+}
+
+impl GtinParts {
+    /// Packaging indicator, which is 0 for anything other than a GTIN-14
+    pub fn indicator(&self) -> i8 {
+        self.gtin[0]
+    }
+
+    /// GS1 company prefix licensed to the brand owner, in its GTIN-13 form
+    /// so a UPC company prefix starts with 0
+    pub fn company_prefix(&self) -> &[i8] {
+        &self.gtin[1..1 + self.prefix_len]
+    }
+
+    /// Item reference, assigned by the brand owner to each of its products
+    pub fn item_reference(&self) -> &[i8] {
+        &self.gtin[1 + self.prefix_len..]
+    }
+
+    /// Check digit from the code, which isn't checked
+    pub fn check_digit(&self) -> i8 {
+        self.check_digit
+    }
+}
+
+impl fmt::Display for GtinParts {
+    /// Formats the indicator, company prefix, item reference and check digit
+    /// separated with hyphens, such as `0-0614141-00003-6`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-", self.indicator())?;
+
+        for element in [self.company_prefix(), self.item_reference()].iter() {
+            for digit in element.iter() {
+                write!(f, "{}", digit)?;
+            }
+
+            f.write_str("-")?;
+        }
+
+        write!(f, "{}", self.check_digit)
+    }
+}
+
+impl Upc {
+    /// Splits this code into its company prefix and item reference, given
+    /// the length of its company prefix in GTIN-13 form (4 to 12 digits).
+    ///
+    /// An EAN-8 doesn't contain a company prefix so it fails with
+    /// [UpcError::InvalidPrefix], whilst a length outside of 4 to 12 fails
+    /// with [UpcError::InvalidPrefixLength].
+    pub fn gtin_parts(&self, prefix_len: usize) -> Result<GtinParts, UpcError> {
+        if !(4..=12).contains(&prefix_len) {
+            return Err(UpcError::InvalidPrefixLength(prefix_len));
+        }
+
+        if let Standard::Ean8(_) = self.upc {
+            return Err(UpcError::InvalidPrefix);
+        }
+
+        self.validate_upc_overflow()?;
+
+        Ok(GtinParts {
+            gtin: self.gtin14_digits(),
+            check_digit: self.check_digit,
+            prefix_len,
+        })
+    }
+
+    /// Splits this code into its company prefix and item reference, looking
+    /// up the length of its company prefix in the embedded GS1 company
+    /// prefix table. Fails with [UpcError::UnknownCompanyPrefix] if it isn't
+    /// in the table or the code doesn't carry a company prefix, such as a
+    /// restricted circulation number. Until the table is generated from
+    /// GS1's list, every code fails.
+    pub fn lookup_gtin_parts(&self) -> Result<GtinParts, UpcError> {
+        self.validate_upc_overflow()?;
+
+        let gtin = self.gtin14_digits();
+
+        let prefix_len = PREFIX_LENGTHS
+            .iter()
+            .filter(|entry| {
+                entry
+                    .prefix
+                    .bytes()
+                    .zip(&gtin[1..])
+                    .all(|(expected, digit)| expected == b'0' + *digit as u8)
+            })
+            .max_by_key(|entry| entry.prefix.len())
+            .map(|entry| entry.length)
+            .filter(|length| *length != 0)
+            .ok_or(UpcError::UnknownCompanyPrefix)?;
+
+        self.gtin_parts(prefix_len)
+    }
+
+    /// Checks whether this code was issued under the given GS1 company
+    /// prefix, written in its GTIN-13 form such as `[0, 6, 1, 4, 1, 4, 1]`
+    /// for the UPC company prefix 614141.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::Upc;
+    ///
+    /// let code: Upc = "614141000036".parse().unwrap();
+    ///
+    /// assert!(code.has_company_prefix(&[0, 6, 1, 4, 1, 4, 1]));
+    /// assert!(!code.has_company_prefix(&[0, 6, 1, 4, 1, 4, 2]));
+    /// ```
+    pub fn has_company_prefix(&self, company_prefix: &[i8]) -> bool {
+        match self.gtin_parts(company_prefix.len()) {
+            Ok(parts) => parts.company_prefix() == company_prefix,
+            Err(_) => false,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Legacy coupon codes, being UPC-A codes with number system 5 and EAN-13
+//! codes with a 99 prefix.
+
+use crate::{Standard, Upc, UpcError};
+
+/// Which kind of code a [Coupon] is held in
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CouponFormat {
+    /// UPC-A with number system 5
+    UpcA,
+
+    /// EAN-13 with a prefix of 99
+    Ean13,
+}
+
+/// Fields of a legacy coupon code, laid out as a manufacturer code, family
+/// code and value code after the prefix.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::{Coupon, CouponFormat, Upc};
+///
+/// let code: Upc = "5 12345 678 01 6".parse().unwrap();
+///
+/// assert_eq!(
+///     Ok(Coupon {
+///         format: CouponFormat::UpcA,
+///         manufacturer: [1, 2, 3, 4, 5],
+///         family: [6, 7, 8],
+///         value_code: [0, 1],
+///     }),
+///     code.coupon()
+/// );
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Coupon {
+    /// Kind of code this is held in
+    pub format: CouponFormat,
+
+    /// Manufacturer code, matching the one in the manufacturer's product
+    /// codes
+    pub manufacturer: [i8; 5],
+
+    /// Family code, selecting which of the manufacturer's products the coupon
+    /// applies to
+    pub family: [i8; 3],
+
+    /// Value code, looked up in the coupon value code table to find the
+    /// discount
+    pub value_code: [i8; 2],
+}
+
+impl Coupon {
+    /// Generates the code for this coupon, computing the check digit.
+    pub fn to_upc(&self) -> Result<Upc, UpcError> {
+        let mut body = [0; 10];
+        body[..5].copy_from_slice(&self.manufacturer);
+        body[5..8].copy_from_slice(&self.family);
+        body[8..].copy_from_slice(&self.value_code);
+
+        let upc = match self.format {
+            CouponFormat::UpcA => {
+                let mut upc = [5; 11];
+                upc[1..].copy_from_slice(&body);
+
+                Standard::UpcA(upc)
+            }
+            CouponFormat::Ean13 => {
+                let mut ean = [9; 12];
+                ean[2..].copy_from_slice(&body);
+
+                Standard::Ean13(ean)
+            }
+        };
+
+        Upc::new(upc)
+    }
+}
+
+impl Upc {
+    /// Splits a coupon code into its manufacturer, family and value codes.
+    ///
+    /// Fails with [UpcError::InvalidPrefix] for codes which aren't a UPC-A
+    /// with number system 5 or an EAN-13 with a 99 prefix. The check digit
+    /// isn't verified, so use [Upc::check] for that.
+    pub fn coupon(&self) -> Result<Coupon, UpcError> {
+        self.validate_upc_overflow()?;
+
+        let ean = match self.to_ean13() {
+            Some(Upc {
+                upc: Standard::Ean13(ean),
+                ..
+            }) => ean,
+            _ => return Err(UpcError::InvalidPrefix),
+        };
+
+        let format = match ean[..2] {
+            [0, 5] => CouponFormat::UpcA,
+            [9, 9] => CouponFormat::Ean13,
+            _ => return Err(UpcError::InvalidPrefix),
+        };
+
+        let mut coupon = Coupon {
+            format,
+            manufacturer: [0; 5],
+            family: [0; 3],
+            value_code: [0; 2],
+        };
+        coupon.manufacturer.copy_from_slice(&ean[2..7]);
+        coupon.family.copy_from_slice(&ean[7..10]);
+        coupon.value_code.copy_from_slice(&ean[10..]);
+
+        Ok(coupon)
+    }
+}
@@ -0,0 +1,82 @@
+//! Formatting of [Upc] codes as their digit strings, either plain or grouped
+//! like the human-readable text printed under barcodes.
+
+use crate::{Standard, Upc};
+use core::fmt;
+
+impl Standard {
+    /// Sizes of the groups the full code (including its check digit) is split
+    /// into when printed under its barcode.
+    fn groups(&self) -> &'static [usize] {
+        match self {
+            Standard::UpcA(_) => &[1, 5, 5, 1],
+            Standard::Ean13(_) => &[1, 6, 6],
+            Standard::Ean8(_) => &[4, 4],
+            Standard::UpcE(_) => &[1, 6, 1],
+            Standard::Gtin14(_) => &[1, 2, 5, 5, 1],
+        }
+    }
+}
+
+impl fmt::Display for Standard {
+    /// Formats the payload digits of this code, without a check digit.
+    ///
+    /// The alternate `{:#}` flag groups the digits as they are printed under
+    /// the barcode.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_digits(f, self.get_slice().iter().copied(), self.groups())
+    }
+}
+
+impl fmt::Display for Upc {
+    /// Formats the digits of this code followed by its check digit, such as
+    /// `036000241457`.
+    ///
+    /// The alternate `{:#}` flag groups the digits as they are printed under
+    /// the barcode, such as `0 36000 24145 7` for UPC-A.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::Upc;
+    ///
+    /// let code: Upc = "036000241457".parse().unwrap();
+    ///
+    /// assert_eq!("036000241457", format!("{}", code));
+    /// assert_eq!("0 36000 24145 7", format!("{:#}", code));
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = self.upc.get_slice().iter().copied();
+
+        write_digits(
+            f,
+            digits.chain(core::iter::once(self.check_digit)),
+            self.upc.groups(),
+        )
+    }
+}
+
+/// Writes each digit to the formatter, separating them by `groups` with spaces
+/// if the alternate flag is set.
+fn write_digits(
+    f: &mut fmt::Formatter,
+    digits: impl Iterator<Item = i8>,
+    groups: &[usize],
+) -> fmt::Result {
+    let mut group_ends = groups.iter().scan(0, |end, len| {
+        *end += len;
+        Some(*end)
+    });
+    let mut group_end = group_ends.next();
+
+    for (ind, digit) in digits.enumerate() {
+        if f.alternate() && ind != 0 && Some(ind) == group_end {
+            f.write_str(" ")?;
+            group_end = group_ends.next();
+        }
+
+        write!(f, "{}", digit)?;
+    }
+
+    Ok(())
+}
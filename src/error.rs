@@ -0,0 +1,164 @@
+//! Errors returned whilst checking, converting or parsing [Upc] codes.
+
+use core::fmt;
+
+/// Possible errors for [Upc](crate::Upc)-based checkin
+#[derive(Debug, PartialEq, Clone)]
+pub enum UpcError {
+    /// Given i8 array has overflown with data that is not 0-9 (1 digit)
+    UpcOverflow {
+        /// Index of the offending digit in the code, counting from 0
+        position: usize,
+
+        /// Value which was found
+        found: i8,
+    },
+
+    /// Given i8 check digit has overflown with data that is not 0-9 (1 digit),
+    /// or 0-10 for an [Isbn10](crate::Isbn10)
+    CheckDigitOverflow {
+        /// Value which was found
+        found: i8,
+    },
+
+    /// Given [Standard::UpcE](crate::Standard::UpcE) has a number system
+    /// other than 0 or 1
+    InvalidNumberSystem {
+        /// Number system which was found
+        found: i8,
+    },
+
+    /// Parsed string contains a character which isn't a digit or separator
+    InvalidCharacter {
+        /// Character index of the offending character in the string
+        position: usize,
+
+        /// Character which was found
+        found: char,
+    },
+
+    /// Parsed string contains a number of digits that doesn't match any
+    /// [Standard](crate::Standard), with the number of digits found
+    InvalidLength(usize),
+
+    /// Code doesn't start with the prefix required for its type, such as
+    /// 978 or 979 for an [Isbn13](crate::Isbn13)
+    InvalidPrefix,
+
+    /// ISBN's registration group or registrant isn't in the
+    /// ISBN range table so it can't be hyphenated
+    UnknownRange,
+
+    /// Variable-measure code's price check digit doesn't match its price
+    PriceCheckMismatch {
+        /// Price check digit calculated from the price
+        expected: i8,
+
+        /// Price check digit in the code
+        found: i8,
+    },
+
+    /// Number is too large to fit into its digits in a code, with the number
+    /// which was given
+    ValueTooLarge(u64),
+
+    /// Recovered string doesn't contain exactly one unknown `?` digit, with
+    /// the number of unknown digits found
+    UnknownDigits(usize),
+
+    /// No digit at the unknown position gives a valid code
+    UnrecoverableDigit {
+        /// Index of the unknown digit in the code, counting from 0
+        position: usize,
+    },
+
+    /// More than one digit at the unknown position gives a valid code
+    AmbiguousDigit {
+        /// Index of the unknown digit in the code, counting from 0
+        position: usize,
+    },
+
+    /// NDC's segments don't match any [NdcFormat](crate::NdcFormat), or
+    /// its HIPAA form doesn't fit the given format
+    InvalidNdcFormat,
+
+    /// GS1 company prefix length is outside of 4 to 12 digits, with the
+    /// length which was given
+    InvalidPrefixLength(usize),
+
+    /// Code's GS1 company prefix isn't in the company prefix table so its
+    /// length is unknown
+    UnknownCompanyPrefix,
+
+    /// Every item or serial reference under a company prefix has already
+    /// been allocated
+    CapacityExhausted,
+
+    /// Serial component of a GS1 key is empty or longer than the key allows
+    InvalidSerialLength {
+        /// Longest serial allowed for the key
+        max: usize,
+
+        /// Number of characters which were found
+        found: usize,
+    },
+}
+
+impl fmt::Display for UpcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UpcError::UpcOverflow { position, found } => write!(
+                f,
+                "digit at position {} is {}, expected 0-9",
+                position, found
+            ),
+            UpcError::CheckDigitOverflow { found } => {
+                write!(f, "check digit {} is out of range", found)
+            }
+            UpcError::InvalidNumberSystem { found } => {
+                write!(f, "number system is {}, expected 0 or 1", found)
+            }
+            UpcError::InvalidCharacter { position, found } => {
+                write!(f, "invalid character {:?} at position {}", found, position)
+            }
+            UpcError::InvalidLength(len) => {
+                write!(f, "found {} digits which doesn't match any standard", len)
+            }
+            UpcError::InvalidPrefix => f.write_str("code doesn't start with the required prefix"),
+            UpcError::UnknownRange => f.write_str("ISBN isn't in a known range"),
+            UpcError::PriceCheckMismatch { expected, found } => {
+                write!(f, "price check digit is {}, expected {}", found, expected)
+            }
+            UpcError::ValueTooLarge(found) => {
+                write!(f, "{} is too large to fit in the code", found)
+            }
+            UpcError::UnknownDigits(count) => {
+                write!(f, "found {} unknown digits, expected exactly 1", count)
+            }
+            UpcError::UnrecoverableDigit { position } => {
+                write!(f, "no digit at position {} gives a valid code", position)
+            }
+            UpcError::AmbiguousDigit { position } => write!(
+                f,
+                "more than one digit at position {} gives a valid code",
+                position
+            ),
+            UpcError::InvalidNdcFormat => {
+                f.write_str("NDC doesn't match a 4-4-2, 5-3-2 or 5-4-1 format")
+            }
+            UpcError::InvalidPrefixLength(len) => {
+                write!(f, "company prefix length is {}, expected 4-12", len)
+            }
+            UpcError::UnknownCompanyPrefix => f.write_str("company prefix isn't in a known table"),
+            UpcError::CapacityExhausted => {
+                f.write_str("every item reference under the company prefix is used")
+            }
+            UpcError::InvalidSerialLength { max, found } => {
+                write!(f, "serial is {} characters long, expected 1-{}", found, max)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UpcError {}
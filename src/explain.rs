@@ -0,0 +1,132 @@
+//! Diagnostics explaining how a [Upc]'s check digit is calculated, so the
+//! reason behind an invalid code can be shown.
+
+use crate::gs1::weight;
+use crate::{Upc, UpcError};
+use core::fmt;
+
+/// Largest number of digits a check digit is calculated from
+const MAX_PAYLOAD: usize = 13;
+
+/// Single digit's part in the weighted sum of a [CheckReport]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Contribution {
+    /// Index of the digit, counting from 0
+    pub position: usize,
+
+    /// Digit at this position
+    pub digit: i8,
+
+    /// Weight the digit was multiplied by, either 1 or 3
+    pub weight: u16,
+
+    /// Resulting value added to the weighted sum
+    pub value: u16,
+}
+
+/// Breakdown of a [Upc]'s check digit calculation, created by [Upc::explain]
+///
+/// UPC-E codes are calculated from their expanded UPC-A form, so
+/// [CheckReport::contributions] refers to the positions of the UPC-A.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckReport {
+    /// Check digit the code should have
+    pub expected: i8,
+
+    /// Check digit the code was given
+    pub found: i8,
+
+    /// Sum of every contribution, which `expected` brings up to the next
+    /// multiple of 10
+    pub weighted_sum: u16,
+
+    contributions: [Contribution; MAX_PAYLOAD],
+    len: usize,
+}
+
+impl CheckReport {
+    /// Per-position contributions to the weighted sum, from left to right
+    pub fn contributions(&self) -> &[Contribution] {
+        &self.contributions[..self.len]
+    }
+
+    /// Checks if the found check digit is the expected one
+    pub fn is_valid(&self) -> bool {
+        self.expected == self.found
+    }
+}
+
+impl fmt::Display for CheckReport {
+    /// Formats a short summary such as `expected 7, got 2`.
+    ///
+    /// The alternate `{:#}` flag adds the weighted sum along with each
+    /// `digit×weight` contribution.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected {}, got {}", self.expected, self.found)?;
+
+        if f.alternate() {
+            write!(f, " (weighted sum {} = ", self.weighted_sum)?;
+
+            for (ind, contribution) in self.contributions().iter().enumerate() {
+                if ind != 0 {
+                    f.write_str(" + ")?;
+                }
+
+                write!(f, "{}×{}", contribution.digit, contribution.weight)?;
+            }
+
+            f.write_str(")")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Upc {
+    /// Explains the check digit calculation of this code, giving the expected
+    /// check digit, the weighted sum and each position's contribution to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::Upc;
+    ///
+    /// let code: Upc = "036000241452".parse().unwrap();
+    /// let report = code.explain().unwrap();
+    ///
+    /// assert!(!report.is_valid());
+    /// assert_eq!("expected 7, got 2", format!("{}", report));
+    /// ```
+    pub fn explain(&self) -> Result<CheckReport, UpcError> {
+        self.validate_upc_overflow()?;
+
+        let mut contributions = [Contribution::default(); MAX_PAYLOAD];
+
+        let len = self.upc.with_check_slice(|digits| {
+            for (position, digit) in digits.iter().enumerate() {
+                let weight = weight(position, digits.len());
+
+                contributions[position] = Contribution {
+                    position,
+                    digit: *digit,
+                    weight,
+                    value: *digit as u16 * weight,
+                };
+            }
+
+            digits.len()
+        });
+
+        // payloads are short enough for the sum of their contributions to
+        // always fit in a u16
+        let weighted_sum = contributions[..len].iter().map(|x| x.value).sum();
+
+        Ok(CheckReport {
+            expected: self.upc.calculate_check_digit(),
+            found: self.check_digit,
+            weighted_sum,
+            contributions,
+            len,
+        })
+    }
+}
@@ -0,0 +1,115 @@
+//! GDTIs identifying documents such as invoices, certificates and forms.
+
+use crate::serial::{read_key_digits, strip_ai};
+use crate::{gs1_check, gs1_check_digit, validate_digits, Serial, UpcError};
+use core::fmt;
+use core::str::FromStr;
+
+/// GS1 application identifier printed before a GDTI
+const APPLICATION_IDENTIFIER: &str = "(253)";
+
+/// Longest serial component of a GDTI
+const MAX_SERIAL_LEN: usize = 17;
+
+/// A [GDTI](https://www.gs1.org/standards/id-keys/gdti) (Global Document
+/// Type Identifier), made up of a GS1 company prefix and document type
+/// followed by a check digit, along with an optional serial component for
+/// telling individual documents of the same type apart.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::Gdti;
+///
+/// let gdti: Gdti = "(253) 0614141000029 INV-42".parse().unwrap();
+///
+/// assert_eq!(Ok(true), gdti.check());
+/// assert_eq!("0614141000029INV-42", gdti.to_string());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gdti {
+    /// Company prefix and document type, being the first 12 digits
+    pub gdti: [i8; 12],
+
+    /// Check digit for verification
+    pub check_digit: i8,
+
+    /// Serial component of up to 17 characters, if the individual document
+    /// is identified
+    pub serial: Option<Serial>,
+}
+
+impl Gdti {
+    /// Creates a new [Gdti] from the 12 digits of its company prefix and
+    /// document type, computing the check digit for it.
+    pub fn new(gdti: [i8; 12], serial: Option<Serial>) -> Result<Self, UpcError> {
+        if let Some(serial) = &serial {
+            serial.validate(MAX_SERIAL_LEN)?;
+        }
+
+        Ok(Self {
+            gdti,
+            check_digit: gs1_check_digit(&gdti)?,
+            serial,
+        })
+    }
+
+    /// Checks given GDTI passed, including that its serial component is no
+    /// longer than 17 characters
+    pub fn check(&self) -> Result<bool, UpcError> {
+        validate_digits(&self.gdti)?;
+
+        if let Some(serial) = &self.serial {
+            serial.validate(MAX_SERIAL_LEN)?;
+        }
+
+        gs1_check(&self.gdti, self.check_digit)
+    }
+}
+
+impl FromStr for Gdti {
+    type Err = UpcError;
+
+    /// Parses a GDTI from its 13 printed digits and the optional serial
+    /// component directly after them. The `(253)` application identifier
+    /// may be given in front.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = [0; 13];
+        let serial = read_key_digits(strip_ai(s, APPLICATION_IDENTIFIER), &mut digits)?;
+
+        let mut gdti = [0; 12];
+        gdti.copy_from_slice(&digits[..12]);
+
+        Ok(Gdti {
+            gdti,
+            check_digit: digits[12],
+            serial: match serial {
+                "" => None,
+                serial => Some(Serial::parse(serial, MAX_SERIAL_LEN)?),
+            },
+        })
+    }
+}
+
+impl fmt::Display for Gdti {
+    /// Formats the 13 digits and serial component of this GDTI.
+    ///
+    /// The alternate `{:#}` flag adds the `(253)` application identifier in
+    /// front.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(APPLICATION_IDENTIFIER)?;
+        }
+
+        for digit in self.gdti.iter() {
+            write!(f, "{}", digit)?;
+        }
+
+        write!(f, "{}", self.check_digit)?;
+
+        match &self.serial {
+            Some(serial) => write!(f, "{}", serial),
+            None => Ok(()),
+        }
+    }
+}
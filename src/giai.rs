@@ -0,0 +1,94 @@
+//! GIAIs identifying fixed assets such as equipment, tools and vehicles.
+
+use crate::serial::strip_ai;
+use crate::{Serial, UpcError};
+use core::fmt;
+use core::str::FromStr;
+
+/// GS1 application identifier printed before a GIAI
+const APPLICATION_IDENTIFIER: &str = "(8004)";
+
+/// Longest GIAI, including its company prefix
+const MAX_LEN: usize = 30;
+
+/// Shortest GS1 company prefix, which every GIAI starts with
+const MIN_PREFIX_LEN: usize = 4;
+
+/// A [GIAI](https://www.gs1.org/standards/id-keys/giai) (Global Individual
+/// Asset Identifier), made up of a GS1 company prefix followed by an
+/// alphanumeric individual asset reference, up to 30 characters in all.
+///
+/// Unlike the other GS1 keys a GIAI has no check digit, so it's validated
+/// when parsed instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::Giai;
+///
+/// let giai: Giai = "(8004) 0614141ABC-123".parse().unwrap();
+///
+/// assert!(giai.has_company_prefix(&[0, 6, 1, 4, 1, 4, 1]));
+/// assert_eq!("(8004)0614141ABC-123", format!("{:#}", giai));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Giai {
+    giai: Serial,
+}
+
+impl Giai {
+    /// Gets the characters of this GIAI.
+    pub fn as_str(&self) -> &str {
+        self.giai.as_str()
+    }
+
+    /// Checks whether this GIAI was issued under the given GS1 company
+    /// prefix.
+    pub fn has_company_prefix(&self, company_prefix: &[i8]) -> bool {
+        let mut chars = self.as_str().chars();
+
+        company_prefix
+            .iter()
+            .all(|digit| chars.next().and_then(|x| x.to_digit(10)) == Some(*digit as u32))
+    }
+}
+
+impl FromStr for Giai {
+    type Err = UpcError;
+
+    /// Parses a GIAI of up to 30 characters, optionally preceded by the
+    /// `(8004)` application identifier.
+    ///
+    /// Fails with [UpcError::InvalidCharacter] if it doesn't start with at
+    /// least 4 digits for its company prefix or contains a character outside
+    /// of GS1's character set 82.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = strip_ai(s, APPLICATION_IDENTIFIER);
+        let giai = Serial::parse(s, MAX_LEN)?;
+
+        for (position, found) in s.chars().take(MIN_PREFIX_LEN).enumerate() {
+            if !found.is_ascii_digit() {
+                return Err(UpcError::InvalidCharacter { position, found });
+            }
+        }
+
+        match s.len() {
+            len if len < MIN_PREFIX_LEN => Err(UpcError::InvalidLength(len)),
+            _ => Ok(Giai { giai }),
+        }
+    }
+}
+
+impl fmt::Display for Giai {
+    /// Formats the characters of this GIAI.
+    ///
+    /// The alternate `{:#}` flag adds the `(8004)` application identifier in
+    /// front.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(APPLICATION_IDENTIFIER)?;
+        }
+
+        f.write_str(self.as_str())
+    }
+}
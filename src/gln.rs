@@ -0,0 +1,91 @@
+//! GLNs identifying parties and physical locations such as stores and
+//! warehouse docks.
+
+use crate::parse::read_digits;
+use crate::serial::strip_ai;
+use crate::{gs1_check, gs1_check_digit, UpcError};
+use core::fmt;
+use core::str::FromStr;
+
+/// GS1 application identifier printed before the GLN of a physical location
+const APPLICATION_IDENTIFIER: &str = "(414)";
+
+/// A 13-digit [GLN](https://www.gs1.org/standards/id-keys/gln) (Global
+/// Location Number), made up of a GS1 company prefix and location reference
+/// followed by a check digit.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::Gln;
+///
+/// let gln: Gln = "(414) 0614141000005".parse().unwrap();
+///
+/// assert_eq!(Ok(true), gln.check());
+/// assert_eq!("(414)0614141000005", format!("{:#}", gln));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gln {
+    /// First 12 digits of the GLN
+    pub gln: [i8; 12],
+
+    /// Check digit for verification
+    pub check_digit: i8,
+}
+
+impl Gln {
+    /// Creates a new [Gln] from its first 12 digits, computing the check
+    /// digit for it.
+    pub fn new(gln: [i8; 12]) -> Result<Self, UpcError> {
+        Ok(Self {
+            gln,
+            check_digit: gs1_check_digit(&gln)?,
+        })
+    }
+
+    /// Checks given GLN passed
+    pub fn check(&self) -> Result<bool, UpcError> {
+        gs1_check(&self.gln, self.check_digit)
+    }
+}
+
+impl FromStr for Gln {
+    type Err = UpcError;
+
+    /// Parses a GLN from its 13 printed digits, optionally preceded by the
+    /// `(414)` application identifier. Whitespace and hyphens are ignored as
+    /// with [Upc](crate::Upc).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = [0; 13];
+        match read_digits(strip_ai(s, APPLICATION_IDENTIFIER), &mut digits, false)? {
+            13 => (),
+            len => return Err(UpcError::InvalidLength(len)),
+        }
+
+        let mut gln = [0; 12];
+        gln.copy_from_slice(&digits[..12]);
+
+        Ok(Gln {
+            gln,
+            check_digit: digits[12],
+        })
+    }
+}
+
+impl fmt::Display for Gln {
+    /// Formats the 13 digits of this GLN.
+    ///
+    /// The alternate `{:#}` flag adds the `(414)` application identifier for
+    /// a physical location in front.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(APPLICATION_IDENTIFIER)?;
+        }
+
+        for digit in self.gln.iter() {
+            write!(f, "{}", digit)?;
+        }
+
+        write!(f, "{}", self.check_digit)
+    }
+}
@@ -0,0 +1,128 @@
+//! GRAIs identifying returnable assets such as pallets, kegs and crates.
+
+use crate::serial::{read_key_digits, strip_ai};
+use crate::{gs1_check, gs1_check_digit, validate_digits, Serial, UpcError};
+use core::fmt;
+use core::str::FromStr;
+
+/// GS1 application identifier printed before a GRAI
+const APPLICATION_IDENTIFIER: &str = "(8003)";
+
+/// Longest serial component of a GRAI
+const MAX_SERIAL_LEN: usize = 16;
+
+/// A [GRAI](https://www.gs1.org/standards/id-keys/grai) (Global Returnable
+/// Asset Identifier), made up of a GS1 company prefix and asset type
+/// followed by a check digit, along with an optional serial component for
+/// telling individual assets of the same type apart.
+///
+/// GRAIs are printed with a leading filler digit of 0, which isn't stored.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::Grai;
+///
+/// let grai: Grai = "(8003) 00614141000012 ABC".parse().unwrap();
+///
+/// assert_eq!(Ok(true), grai.check());
+/// assert_eq!(Some("ABC"), grai.serial.as_ref().map(|x| x.as_str()));
+/// assert_eq!("(8003)00614141000012ABC", format!("{:#}", grai));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Grai {
+    /// Company prefix and asset type, being the 12 digits after the filler
+    /// digit
+    pub grai: [i8; 12],
+
+    /// Check digit for verification
+    pub check_digit: i8,
+
+    /// Serial component of up to 16 characters, if the individual asset is
+    /// identified
+    pub serial: Option<Serial>,
+}
+
+impl Grai {
+    /// Creates a new [Grai] from the 12 digits of its company prefix and
+    /// asset type, computing the check digit for it.
+    pub fn new(grai: [i8; 12], serial: Option<Serial>) -> Result<Self, UpcError> {
+        if let Some(serial) = &serial {
+            serial.validate(MAX_SERIAL_LEN)?;
+        }
+
+        Ok(Self {
+            grai,
+            check_digit: gs1_check_digit(&grai)?,
+            serial,
+        })
+    }
+
+    /// Checks given GRAI passed, including that its serial component is no
+    /// longer than 16 characters
+    pub fn check(&self) -> Result<bool, UpcError> {
+        validate_digits(&self.grai)?;
+
+        if let Some(serial) = &self.serial {
+            serial.validate(MAX_SERIAL_LEN)?;
+        }
+
+        gs1_check(&self.grai, self.check_digit)
+    }
+}
+
+impl FromStr for Grai {
+    type Err = UpcError;
+
+    /// Parses a GRAI from its 14 printed digits, starting with the filler
+    /// digit of 0, and the optional serial component directly after them.
+    /// The `(8003)` application identifier may be given in front.
+    ///
+    /// Fails with [UpcError::InvalidPrefix] if the filler digit isn't 0.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = [0; 14];
+        let serial = read_key_digits(strip_ai(s, APPLICATION_IDENTIFIER), &mut digits)?;
+
+        if digits[0] != 0 {
+            return Err(UpcError::InvalidPrefix);
+        }
+
+        let mut grai = [0; 12];
+        grai.copy_from_slice(&digits[1..13]);
+
+        Ok(Grai {
+            grai,
+            check_digit: digits[13],
+            serial: match serial {
+                "" => None,
+                serial => Some(Serial::parse(serial, MAX_SERIAL_LEN)?),
+            },
+        })
+    }
+}
+
+impl fmt::Display for Grai {
+    /// Formats the filler digit, 13 digits and serial component of this
+    /// GRAI.
+    ///
+    /// The alternate `{:#}` flag adds the `(8003)` application identifier in
+    /// front.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(APPLICATION_IDENTIFIER)?;
+        }
+
+        f.write_str("0")?;
+
+        for digit in self.grai.iter() {
+            write!(f, "{}", digit)?;
+        }
+
+        write!(f, "{}", self.check_digit)?;
+
+        match &self.serial {
+            Some(serial) => write!(f, "{}", serial),
+            None => Ok(()),
+        }
+    }
+}
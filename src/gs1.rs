@@ -0,0 +1,77 @@
+//! GS1 modulo-10 check digit engine shared by every GS1 identifier, from
+//! [Upc](crate::Upc) codes through to SSCCs and GLNs.
+
+use crate::{is_1_digit, validate_digits, UpcError};
+
+/// Calculates the GS1 modulo-10 check digit for any number of digits, such
+/// as the digits of a GS1 key which has no dedicated type.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::gs1_check_digit;
+///
+/// assert_eq!(Ok(2), gs1_check_digit(&[0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]));
+/// ```
+pub fn gs1_check_digit(digits: &[i8]) -> Result<i8, UpcError> {
+    validate_digits(digits)?;
+
+    Ok(calculate_check_digit(digits))
+}
+
+/// Checks the given GS1 modulo-10 check digit is correct for the digits
+/// before it, in the same way as [Upc::check](crate::Upc::check).
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::gs1_check;
+///
+/// assert_eq!(Ok(true), gs1_check(&[0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5], 2));
+/// assert_eq!(Ok(false), gs1_check(&[0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5], 3));
+/// ```
+pub fn gs1_check(digits: &[i8], check_digit: i8) -> Result<bool, UpcError> {
+    validate_digits(digits)?;
+
+    if !is_1_digit(check_digit) {
+        return Err(UpcError::CheckDigitOverflow { found: check_digit });
+    }
+
+    Ok(calculate_check_digit(digits) == check_digit)
+}
+
+/// Sums the given digits by their position using the GS1 modulo-10 weighting.
+///
+/// Weighting is counted from the right-hand side, so the digit directly
+/// before the check digit is always multiplied by 3 and the rest alternate
+/// between 1 and 3 leftwards from there. For
+/// [Standard::UpcA](crate::Standard::UpcA) this is the familiar rule of
+/// positions 1, 3, 5 .. 11 being weighted ×3, whereas
+/// [Standard::Ean13](crate::Standard::Ean13) weights its even positions and
+/// [Standard::Ean8](crate::Standard::Ean8) its odd positions 1 .. 7.
+///
+/// The sum is kept in a `u32` as [gs1_check_digit] takes any number of
+/// digits, which is enough for slices of over 100 million digits.
+pub(crate) fn weighted_sum(digits: &[i8]) -> u32 {
+    digits
+        .iter()
+        .enumerate()
+        .map(|(position, digit)| *digit as u32 * weight(position, digits.len()) as u32)
+        .sum()
+}
+
+/// Gets the weight (1 or 3) of the digit at `position` out of `len` digits
+/// preceding a check digit, as used by [weighted_sum].
+pub(crate) fn weight(position: usize, len: usize) -> u16 {
+    if (len - position) % 2 == 1 {
+        3
+    } else {
+        1
+    }
+}
+
+/// Calculates the modulo-10 check digit for the given (already validated)
+/// digits using [weighted_sum].
+pub(crate) fn calculate_check_digit(digits: &[i8]) -> i8 {
+    ((10 - weighted_sum(digits) % 10) % 10) as i8
+}
@@ -0,0 +1,167 @@
+//! GS1 prefix table, mapping the first 3 digits of a GTIN-13 to the GS1
+//! member organization which issued it, compiled from the
+//! [GS1 Company Prefix list](https://www.gs1.org/standards/id-keys/company-prefix).
+//!
+//! Member organizations issue prefixes to companies in their country, but a
+//! product can be made anywhere, so this is the origin of the company
+//! prefix rather than of the product. Restricted and special prefixes aren't
+//! listed as they're described by [Classification](crate::Classification).
+//!
+//! [PREFIXES] matches the GS1 prefix list as published in 2024, and has to
+//! be updated by hand as GS1 has no machine-readable version of it. Prefixes
+//! issued since then are classified as
+//! [Classification::Unassigned](crate::Classification::Unassigned) until
+//! they're added here.
+
+/// Member organization for the 3-digit prefixes `start..=end`
+pub(crate) struct Prefix {
+    pub(crate) start: u16,
+    pub(crate) end: u16,
+    pub(crate) country: &'static str,
+}
+
+/// Shorthand for writing a [Prefix] range
+const fn prefix(start: u16, end: u16, country: &'static str) -> Prefix {
+    Prefix {
+        start,
+        end,
+        country,
+    }
+}
+
+/// Every member organization prefix, in ascending order
+pub(crate) static PREFIXES: &[Prefix] = &[
+    prefix(0, 19, "United States and Canada"),
+    prefix(30, 39, "United States and Canada"),
+    prefix(50, 59, "United States and Canada"),
+    prefix(60, 139, "United States and Canada"),
+    prefix(300, 379, "France and Monaco"),
+    prefix(380, 380, "Bulgaria"),
+    prefix(383, 383, "Slovenia"),
+    prefix(385, 385, "Croatia"),
+    prefix(387, 387, "Bosnia and Herzegovina"),
+    prefix(389, 389, "Montenegro"),
+    prefix(390, 390, "Kosovo"),
+    prefix(400, 440, "Germany"),
+    prefix(450, 459, "Japan"),
+    prefix(460, 469, "Russia"),
+    prefix(470, 470, "Kyrgyzstan"),
+    prefix(471, 471, "Taiwan"),
+    prefix(474, 474, "Estonia"),
+    prefix(475, 475, "Latvia"),
+    prefix(476, 476, "Azerbaijan"),
+    prefix(477, 477, "Lithuania"),
+    prefix(478, 478, "Uzbekistan"),
+    prefix(479, 479, "Sri Lanka"),
+    prefix(480, 480, "Philippines"),
+    prefix(481, 481, "Belarus"),
+    prefix(482, 482, "Ukraine"),
+    prefix(483, 483, "Turkmenistan"),
+    prefix(484, 484, "Moldova"),
+    prefix(485, 485, "Armenia"),
+    prefix(486, 486, "Georgia"),
+    prefix(487, 487, "Kazakhstan"),
+    prefix(488, 488, "Tajikistan"),
+    prefix(489, 489, "Hong Kong"),
+    prefix(490, 499, "Japan"),
+    prefix(500, 509, "United Kingdom"),
+    prefix(520, 521, "Greece"),
+    prefix(528, 528, "Lebanon"),
+    prefix(529, 529, "Cyprus"),
+    prefix(530, 530, "Albania"),
+    prefix(531, 531, "North Macedonia"),
+    prefix(535, 535, "Malta"),
+    prefix(539, 539, "Ireland"),
+    prefix(540, 549, "Belgium and Luxembourg"),
+    prefix(560, 560, "Portugal"),
+    prefix(569, 569, "Iceland"),
+    prefix(570, 579, "Denmark, Faroe Islands and Greenland"),
+    prefix(590, 590, "Poland"),
+    prefix(594, 594, "Romania"),
+    prefix(599, 599, "Hungary"),
+    prefix(600, 601, "South Africa"),
+    prefix(603, 603, "Ghana"),
+    prefix(604, 604, "Senegal"),
+    prefix(607, 607, "Oman"),
+    prefix(608, 608, "Bahrain"),
+    prefix(609, 609, "Mauritius"),
+    prefix(611, 611, "Morocco"),
+    prefix(613, 613, "Algeria"),
+    prefix(615, 615, "Nigeria"),
+    prefix(616, 616, "Kenya"),
+    prefix(617, 617, "Cameroon"),
+    prefix(618, 618, "Côte d'Ivoire"),
+    prefix(619, 619, "Tunisia"),
+    prefix(620, 620, "Tanzania"),
+    prefix(621, 621, "Syria"),
+    prefix(622, 622, "Egypt"),
+    prefix(623, 623, "Brunei"),
+    prefix(624, 624, "Libya"),
+    prefix(625, 625, "Jordan"),
+    prefix(626, 626, "Iran"),
+    prefix(627, 627, "Kuwait"),
+    prefix(628, 628, "Saudi Arabia"),
+    prefix(629, 629, "United Arab Emirates"),
+    prefix(630, 630, "Qatar"),
+    prefix(631, 631, "Namibia"),
+    prefix(640, 649, "Finland"),
+    prefix(680, 681, "China"),
+    prefix(690, 699, "China"),
+    prefix(700, 709, "Norway"),
+    prefix(729, 729, "Israel"),
+    prefix(730, 739, "Sweden"),
+    prefix(740, 740, "Guatemala"),
+    prefix(741, 741, "El Salvador"),
+    prefix(742, 742, "Honduras"),
+    prefix(743, 743, "Nicaragua"),
+    prefix(744, 744, "Costa Rica"),
+    prefix(745, 745, "Panama"),
+    prefix(746, 746, "Dominican Republic"),
+    prefix(750, 750, "Mexico"),
+    prefix(754, 755, "Canada"),
+    prefix(759, 759, "Venezuela"),
+    prefix(760, 769, "Switzerland and Liechtenstein"),
+    prefix(770, 771, "Colombia"),
+    prefix(773, 773, "Uruguay"),
+    prefix(775, 775, "Peru"),
+    prefix(777, 777, "Bolivia"),
+    prefix(778, 779, "Argentina"),
+    prefix(780, 780, "Chile"),
+    prefix(784, 784, "Paraguay"),
+    prefix(786, 786, "Ecuador"),
+    prefix(789, 790, "Brazil"),
+    prefix(800, 839, "Italy, San Marino and Vatican City"),
+    prefix(840, 849, "Spain and Andorra"),
+    prefix(850, 850, "Cuba"),
+    prefix(858, 858, "Slovakia"),
+    prefix(859, 859, "Czech Republic"),
+    prefix(860, 860, "Serbia"),
+    prefix(865, 865, "Mongolia"),
+    prefix(867, 867, "North Korea"),
+    prefix(868, 869, "Turkey"),
+    prefix(870, 879, "Netherlands"),
+    prefix(880, 880, "South Korea"),
+    prefix(883, 883, "Myanmar"),
+    prefix(884, 884, "Cambodia"),
+    prefix(885, 885, "Thailand"),
+    prefix(888, 888, "Singapore"),
+    prefix(890, 890, "India"),
+    prefix(893, 893, "Vietnam"),
+    prefix(896, 896, "Pakistan"),
+    prefix(899, 899, "Indonesia"),
+    prefix(900, 919, "Austria"),
+    prefix(930, 939, "Australia"),
+    prefix(940, 949, "New Zealand"),
+    prefix(950, 951, "GS1 Global Office"),
+    prefix(955, 955, "Malaysia"),
+    prefix(958, 958, "Macau"),
+    prefix(960, 969, "GS1 Global Office"),
+];
+
+/// Finds the member organization's country for a 3-digit prefix.
+pub(crate) fn country(prefix: u16) -> Option<&'static str> {
+    PREFIXES
+        .iter()
+        .find(|x| (x.start..=x.end).contains(&prefix))
+        .map(|x| x.country)
+}
@@ -0,0 +1,92 @@
+//! GSRNs identifying the recipients and providers of services, such as
+//! loyalty scheme members or hospital patients.
+
+use crate::parse::read_digits;
+use crate::serial::strip_ai;
+use crate::{gs1_check, gs1_check_digit, UpcError};
+use core::fmt;
+use core::str::FromStr;
+
+/// GS1 application identifier printed before the GSRN of a service
+/// recipient
+const APPLICATION_IDENTIFIER: &str = "(8018)";
+
+/// An 18-digit [GSRN](https://www.gs1.org/standards/id-keys/gsrn) (Global
+/// Service Relation Number), made up of a GS1 company prefix and service
+/// reference followed by a check digit.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::Gsrn;
+///
+/// let gsrn: Gsrn = "(8018) 061414112345678902".parse().unwrap();
+///
+/// assert_eq!(Ok(true), gsrn.check());
+/// assert_eq!("061414112345678902", gsrn.to_string());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gsrn {
+    /// First 17 digits of the GSRN
+    pub gsrn: [i8; 17],
+
+    /// Check digit for verification
+    pub check_digit: i8,
+}
+
+impl Gsrn {
+    /// Creates a new [Gsrn] from its first 17 digits, computing the check
+    /// digit for it.
+    pub fn new(gsrn: [i8; 17]) -> Result<Self, UpcError> {
+        Ok(Self {
+            gsrn,
+            check_digit: gs1_check_digit(&gsrn)?,
+        })
+    }
+
+    /// Checks given GSRN passed
+    pub fn check(&self) -> Result<bool, UpcError> {
+        gs1_check(&self.gsrn, self.check_digit)
+    }
+}
+
+impl FromStr for Gsrn {
+    type Err = UpcError;
+
+    /// Parses a GSRN from its 18 printed digits, optionally preceded by the
+    /// `(8018)` application identifier. Whitespace and hyphens are ignored as
+    /// with [Upc](crate::Upc).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = [0; 18];
+        match read_digits(strip_ai(s, APPLICATION_IDENTIFIER), &mut digits, false)? {
+            18 => (),
+            len => return Err(UpcError::InvalidLength(len)),
+        }
+
+        let mut gsrn = [0; 17];
+        gsrn.copy_from_slice(&digits[..17]);
+
+        Ok(Gsrn {
+            gsrn,
+            check_digit: digits[17],
+        })
+    }
+}
+
+impl fmt::Display for Gsrn {
+    /// Formats the 18 digits of this GSRN.
+    ///
+    /// The alternate `{:#}` flag adds the `(8018)` application identifier for
+    /// a service recipient in front.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(APPLICATION_IDENTIFIER)?;
+        }
+
+        for digit in self.gsrn.iter() {
+            write!(f, "{}", digit)?;
+        }
+
+        write!(f, "{}", self.check_digit)
+    }
+}
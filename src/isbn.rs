@@ -0,0 +1,338 @@
+//! ISBN-10 and ISBN-13 (Bookland EAN) validation along with conversion
+//! between them.
+
+use crate::isbn_ranges::{element_length, GROUPS, PREFIXES};
+use crate::parse::read_mod11_digits;
+use crate::{
+    calculate_mod11_check_digit, gs1_check_digit, validate_digits, Standard, Upc, UpcError,
+};
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+/// An [ISBN-10](https://en.wikipedia.org/wiki/International_Standard_Book_Number#ISBN-10_check_digits)
+/// using its modulo-11 check digit, where a check digit of `10` is printed as
+/// `X`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Isbn10 {
+    /// First 9 digits of the ISBN
+    pub isbn: [i8; 9],
+
+    /// Check digit for verification, from 0 to 10 (`X`)
+    pub check_digit: i8,
+}
+
+/// An [ISBN-13](https://en.wikipedia.org/wiki/International_Standard_Book_Number#ISBN-13_check_digit_calculation),
+/// being an EAN-13 in the 978 or 979 "Bookland" prefix.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Isbn13 {
+    /// First 12 digits of the ISBN, starting with 978 or 979
+    pub isbn: [i8; 12],
+
+    /// Check digit for verification
+    pub check_digit: i8,
+}
+
+/// An ISBN split into its prefix, registration group, registrant,
+/// publication and check digit elements, created by [Isbn13::hyphenated] or
+/// [Isbn10::hyphenated].
+///
+/// This is displayed with hyphens between each element, such as
+/// `978-0-306-40615-7`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct HyphenatedIsbn {
+    isbn: [i8; 12],
+    check_digit: i8,
+    isbn10: bool,
+    group_len: usize,
+    registrant_len: usize,
+}
+
+impl HyphenatedIsbn {
+    /// Splits the 978 or 979-prefixed digits of an ISBN into its elements
+    /// using the ISBN range table.
+    fn new(isbn: [i8; 12], check_digit: i8, isbn10: bool) -> Result<Self, UpcError> {
+        validate_isbn13(&isbn)?;
+
+        let group_len =
+            element_length(PREFIXES, &isbn[..3], &isbn[3..]).ok_or(UpcError::UnknownRange)?;
+        let registrant_len = element_length(GROUPS, &isbn[..3 + group_len], &isbn[3 + group_len..])
+            .ok_or(UpcError::UnknownRange)?;
+
+        Ok(Self {
+            isbn,
+            check_digit,
+            isbn10,
+            group_len,
+            registrant_len,
+        })
+    }
+
+    /// Bookland prefix of 978 or 979, which isn't printed for an ISBN-10
+    pub fn prefix(&self) -> Option<&[i8]> {
+        if self.isbn10 {
+            None
+        } else {
+            Some(&self.isbn[..3])
+        }
+    }
+
+    /// Registration group, identifying a country, region or language area
+    pub fn group(&self) -> &[i8] {
+        &self.isbn[3..3 + self.group_len]
+    }
+
+    /// Registrant, identifying the publisher within the registration group
+    pub fn registrant(&self) -> &[i8] {
+        &self.isbn[3 + self.group_len..3 + self.group_len + self.registrant_len]
+    }
+
+    /// Publication, identifying the edition and format of the title
+    pub fn publication(&self) -> &[i8] {
+        &self.isbn[3 + self.group_len + self.registrant_len..]
+    }
+
+    /// Check digit, from 0 to 10 (`X`) for an ISBN-10
+    pub fn check_digit(&self) -> i8 {
+        self.check_digit
+    }
+}
+
+impl fmt::Display for HyphenatedIsbn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(prefix) = self.prefix() {
+            write_digits(f, prefix)?;
+            f.write_str("-")?;
+        }
+
+        for element in [self.group(), self.registrant(), self.publication()].iter() {
+            write_digits(f, element)?;
+            f.write_str("-")?;
+        }
+
+        match self.check_digit {
+            10 => f.write_str("X"),
+            check_digit => write!(f, "{}", check_digit),
+        }
+    }
+}
+
+impl Isbn10 {
+    /// Creates a new [Isbn10] from its first 9 digits, computing the check
+    /// digit for it.
+    pub fn new(isbn: [i8; 9]) -> Result<Self, UpcError> {
+        validate_digits(&isbn)?;
+
+        Ok(Self {
+            isbn,
+            check_digit: calculate_mod11_check_digit(&isbn),
+        })
+    }
+
+    /// Checks given ISBN-10 passed
+    pub fn check(&self) -> Result<bool, UpcError> {
+        validate_digits(&self.isbn)?;
+
+        if !(0..=10).contains(&self.check_digit) {
+            return Err(UpcError::CheckDigitOverflow {
+                found: self.check_digit,
+            });
+        }
+
+        Ok(calculate_mod11_check_digit(&self.isbn) == self.check_digit)
+    }
+
+    /// Converts this ISBN-10 to its 978-prefixed ISBN-13, computing the new
+    /// check digit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::Isbn10;
+    ///
+    /// let isbn: Isbn10 = "0-306-40615-2".parse().unwrap();
+    ///
+    /// assert_eq!("9780306406157", isbn.to_isbn13().unwrap().to_string());
+    /// ```
+    pub fn to_isbn13(&self) -> Result<Isbn13, UpcError> {
+        let mut isbn = [9, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        isbn[3..].copy_from_slice(&self.isbn);
+
+        Isbn13::new(isbn)
+    }
+
+    /// Splits this ISBN into its elements for displaying with hyphens,
+    /// failing with [UpcError::UnknownRange] if its registration group or
+    /// registrant isn't in the range table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::Isbn10;
+    ///
+    /// let isbn: Isbn10 = "0306406152".parse().unwrap();
+    ///
+    /// assert_eq!("0-306-40615-2", isbn.hyphenated().unwrap().to_string());
+    /// ```
+    pub fn hyphenated(&self) -> Result<HyphenatedIsbn, UpcError> {
+        let mut isbn = [9, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        isbn[3..].copy_from_slice(&self.isbn);
+
+        HyphenatedIsbn::new(isbn, self.check_digit, true)
+    }
+}
+
+impl Isbn13 {
+    /// Creates a new [Isbn13] from its first 12 digits, computing the check
+    /// digit for it.
+    pub fn new(isbn: [i8; 12]) -> Result<Self, UpcError> {
+        validate_isbn13(&isbn)?;
+
+        Ok(Self {
+            isbn,
+            check_digit: gs1_check_digit(&isbn)?,
+        })
+    }
+
+    /// Checks given ISBN-13 passed, which must start with 978 or 979
+    pub fn check(&self) -> Result<bool, UpcError> {
+        validate_isbn13(&self.isbn)?;
+
+        Upc::from(self.clone()).check()
+    }
+
+    /// Converts this ISBN-13 to its ISBN-10, computing the new check digit.
+    /// Only 978-prefixed ISBNs have an ISBN-10 so [UpcError::InvalidPrefix]
+    /// is given for 979.
+    pub fn to_isbn10(&self) -> Result<Isbn10, UpcError> {
+        if self.isbn[..3] != [9, 7, 8] {
+            return Err(UpcError::InvalidPrefix);
+        }
+
+        let mut isbn = [0; 9];
+        isbn.copy_from_slice(&self.isbn[3..]);
+
+        Isbn10::new(isbn)
+    }
+
+    /// Splits this ISBN into its elements for displaying with hyphens,
+    /// failing with [UpcError::UnknownRange] if its registration group or
+    /// registrant isn't in the range table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::Isbn13;
+    ///
+    /// let isbn: Isbn13 = "9783161484100".parse().unwrap();
+    ///
+    /// assert_eq!("978-3-16-148410-0", isbn.hyphenated().unwrap().to_string());
+    /// ```
+    pub fn hyphenated(&self) -> Result<HyphenatedIsbn, UpcError> {
+        HyphenatedIsbn::new(self.isbn, self.check_digit, false)
+    }
+}
+
+impl From<Isbn13> for Upc {
+    fn from(isbn: Isbn13) -> Self {
+        Upc {
+            upc: Standard::Ean13(isbn.isbn),
+            check_digit: isbn.check_digit,
+        }
+    }
+}
+
+impl TryFrom<Upc> for Isbn13 {
+    type Error = UpcError;
+
+    /// Converts a scanned Bookland EAN to an [Isbn13], failing with
+    /// [UpcError::InvalidPrefix] if it isn't an EAN-13 starting with 978 or
+    /// 979.
+    fn try_from(upc: Upc) -> Result<Self, Self::Error> {
+        match upc.to_ean13() {
+            Some(Upc {
+                upc: Standard::Ean13(isbn),
+                check_digit,
+            }) => {
+                validate_isbn13(&isbn)?;
+
+                Ok(Isbn13 { isbn, check_digit })
+            }
+            _ => Err(UpcError::InvalidPrefix),
+        }
+    }
+}
+
+impl FromStr for Isbn10 {
+    type Err = UpcError;
+
+    /// Parses an ISBN-10 from its 10 printed digits, allowing a trailing `X`
+    /// check digit. Whitespace and hyphens are ignored as with [Upc].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = [0; 10];
+        read_mod11_digits(s, &mut digits)?;
+
+        let mut isbn = [0; 9];
+        isbn.copy_from_slice(&digits[..9]);
+
+        Ok(Isbn10 {
+            isbn,
+            check_digit: digits[9],
+        })
+    }
+}
+
+impl FromStr for Isbn13 {
+    type Err = UpcError;
+
+    /// Parses an ISBN-13 from its 13 printed digits, in the same way as
+    /// [Upc].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<Upc>()? {
+            upc @ Upc {
+                upc: Standard::Ean13(_),
+                ..
+            } => Isbn13::try_from(upc),
+            upc => Err(UpcError::InvalidLength(upc.upc.get_slice().len() + 1)),
+        }
+    }
+}
+
+impl fmt::Display for Isbn10 {
+    /// Formats the digits of this ISBN, with a check digit of 10 as `X`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_digits(f, &self.isbn)?;
+
+        match self.check_digit {
+            10 => f.write_str("X"),
+            check_digit => write!(f, "{}", check_digit),
+        }
+    }
+}
+
+impl fmt::Display for Isbn13 {
+    /// Formats the digits of this ISBN.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_digits(f, &self.isbn)?;
+        write!(f, "{}", self.check_digit)
+    }
+}
+
+/// Writes each of the digits to the formatter.
+fn write_digits(f: &mut fmt::Formatter, digits: &[i8]) -> fmt::Result {
+    for digit in digits {
+        write!(f, "{}", digit)?;
+    }
+
+    Ok(())
+}
+
+/// Validates the digits of an ISBN-13 along with its Bookland prefix.
+fn validate_isbn13(isbn: &[i8; 12]) -> Result<(), UpcError> {
+    validate_digits(isbn)?;
+
+    match isbn[..3] {
+        [9, 7, 8] | [9, 7, 9] => Ok(()),
+        _ => Err(UpcError::InvalidPrefix),
+    }
+}
@@ -0,0 +1,372 @@
+//! ISBN range table compiled from the ISBN International Agency's
+//! [RangeMessage](https://www.isbn-international.org/range_file_generation)
+//! data, used to hyphenate ISBNs.
+//!
+//! Each rule gives the length of the next element of the ISBN for a range of
+//! the 7 digits which follow the prefix (right-padded with zeros when fewer
+//! remain), exactly as in the `<Rule>` elements of `RangeMessage.xml`. A
+//! length of 0 means the range isn't in use yet.
+//!
+//! [PREFIXES] and [GROUPS] are meant to be generated by running
+//! `tools/isbn_ranges.py` on a `RangeMessage.xml`, which rewrites everything
+//! between the `BEGIN GENERATED TABLES` and `END GENERATED TABLES` markers
+//! and records the serial number of the range file. The tables checked in
+//! now are a partial transcription which hasn't been generated yet, covering
+//! only the 16 registration groups listed; large groups such as 978-5,
+//! 978-84, 978-85, 978-88, 978-93 and 979-8 are missing until they are.
+//! ISBNs in any group missing from the tables give
+//! [UpcError::UnknownRange](crate::UpcError::UnknownRange) when hyphenated,
+//! never a guessed hyphenation.
+
+/// Length of the next element of an ISBN for the 7-digit values in `range`,
+/// written as `start-end`
+pub(crate) struct Rule {
+    pub(crate) range: &'static str,
+    pub(crate) length: usize,
+}
+
+/// Rules for the elements following a `prefix` such as `978` or `978-0`
+pub(crate) struct Group {
+    pub(crate) prefix: &'static str,
+    pub(crate) rules: &'static [Rule],
+}
+
+/// Shorthand for writing a [Rule] from its `<Range>` and `<Length>`
+const fn rule(range: &'static str, length: usize) -> Rule {
+    Rule { range, length }
+}
+
+// BEGIN GENERATED TABLES
+// Transcribed from RangeMessage.xml rather than generated, so only the
+// groups below are covered; run tools/isbn_ranges.py on a current
+// RangeMessage.xml to replace these tables with every group.
+
+/// Registration group lengths for each EAN.UCC (Bookland) prefix
+pub(crate) static PREFIXES: &[Group] = &[
+    // International ISBN Agency
+    Group {
+        prefix: "978",
+        rules: &[
+            rule("0000000-5999999", 1),
+            rule("6000000-6499999", 3),
+            rule("6500000-6599999", 2),
+            rule("6600000-6999999", 0),
+            rule("7000000-7999999", 1),
+            rule("8000000-9499999", 2),
+            rule("9500000-9899999", 3),
+            rule("9900000-9989999", 4),
+            rule("9990000-9999999", 5),
+        ],
+    },
+    // International ISBN Agency
+    Group {
+        prefix: "979",
+        rules: &[
+            rule("0000000-0999999", 0),
+            rule("1000000-1299999", 2),
+            rule("1300000-7999999", 0),
+            rule("8000000-8999999", 1),
+            rule("9000000-9999999", 0),
+        ],
+    },
+];
+
+/// Registrant lengths for each registration group
+pub(crate) static GROUPS: &[Group] = &[
+    // English language
+    Group {
+        prefix: "978-0",
+        rules: &[
+            rule("0000000-1999999", 2),
+            rule("2000000-2279999", 3),
+            rule("2280000-2289999", 4),
+            rule("2290000-3689999", 3),
+            rule("3690000-3699999", 4),
+            rule("3700000-6389999", 3),
+            rule("6390000-6397999", 4),
+            rule("6398000-6399999", 7),
+            rule("6400000-6449999", 3),
+            rule("6450000-6459999", 7),
+            rule("6460000-6479999", 3),
+            rule("6480000-6489999", 7),
+            rule("6490000-6549999", 3),
+            rule("6550000-6559999", 4),
+            rule("6560000-6999999", 3),
+            rule("7000000-8499999", 4),
+            rule("8500000-8999999", 5),
+            rule("9000000-9499999", 6),
+            rule("9500000-9999999", 7),
+        ],
+    },
+    // English language
+    Group {
+        prefix: "978-1",
+        rules: &[
+            rule("0000000-0999999", 2),
+            rule("1000000-3999999", 3),
+            rule("4000000-5499999", 4),
+            rule("5500000-6499999", 5),
+            rule("6500000-6799999", 4),
+            rule("6800000-6859999", 5),
+            rule("6860000-7139999", 4),
+            rule("7140000-7169999", 3),
+            rule("7170000-7319999", 4),
+            rule("7320000-7399999", 7),
+            rule("7400000-7749999", 5),
+            rule("7750000-7753999", 7),
+            rule("7754000-7763999", 5),
+            rule("7764000-7764999", 7),
+            rule("7765000-7769999", 5),
+            rule("7770000-7782999", 7),
+            rule("7783000-7899999", 5),
+            rule("7900000-7999999", 4),
+            rule("8000000-8379999", 5),
+            rule("8380000-8384999", 7),
+            rule("8385000-8671999", 5),
+            rule("8672000-8675999", 4),
+            rule("8676000-8697999", 5),
+            rule("8698000-9159999", 6),
+            rule("9160000-9165059", 7),
+            rule("9165060-9168699", 6),
+            rule("9168700-9169079", 7),
+            rule("9169080-9195999", 6),
+            rule("9196000-9196549", 7),
+            rule("9196550-9729999", 6),
+            rule("9730000-9877999", 4),
+            rule("9878000-9911499", 6),
+            rule("9911500-9911999", 7),
+            rule("9912000-9989899", 6),
+            rule("9989900-9999999", 7),
+        ],
+    },
+    // French language
+    Group {
+        prefix: "978-2",
+        rules: &[
+            rule("0000000-1999999", 2),
+            rule("2000000-3499999", 3),
+            rule("3500000-3999999", 5),
+            rule("4000000-4869999", 3),
+            rule("4870000-4949999", 6),
+            rule("4950000-4959999", 3),
+            rule("4960000-4966999", 4),
+            rule("4967000-4969999", 5),
+            rule("4970000-5279999", 3),
+            rule("5280000-5299999", 4),
+            rule("5300000-6999999", 3),
+            rule("7000000-8399999", 4),
+            rule("8400000-8999999", 5),
+            rule("9000000-9197999", 6),
+            rule("9198000-9198099", 5),
+            rule("9198100-9199429", 6),
+            rule("9199430-9199689", 7),
+            rule("9199690-9499999", 6),
+            rule("9500000-9999999", 7),
+        ],
+    },
+    // German language
+    Group {
+        prefix: "978-3",
+        rules: &[
+            rule("0000000-0299999", 2),
+            rule("0300000-0339999", 3),
+            rule("0340000-0369999", 4),
+            rule("0370000-0399999", 5),
+            rule("0400000-1999999", 2),
+            rule("2000000-6999999", 3),
+            rule("7000000-8499999", 4),
+            rule("8500000-8999999", 5),
+            rule("9000000-9499999", 6),
+            rule("9500000-9539999", 7),
+            rule("9540000-9699999", 5),
+            rule("9700000-9849999", 7),
+            rule("9850000-9999999", 5),
+        ],
+    },
+    // Japan
+    Group {
+        prefix: "978-4",
+        rules: &[
+            rule("0000000-1999999", 2),
+            rule("2000000-6999999", 3),
+            rule("7000000-8499999", 4),
+            rule("8500000-8999999", 5),
+            rule("9000000-9499999", 6),
+            rule("9500000-9999999", 7),
+        ],
+    },
+    // China, People's Republic
+    Group {
+        prefix: "978-7",
+        rules: &[
+            rule("0000000-0999999", 2),
+            rule("1000000-4999999", 3),
+            rule("5000000-7999999", 4),
+            rule("8000000-8999999", 5),
+            rule("9000000-9999999", 6),
+        ],
+    },
+    // former Czechoslovakia
+    Group {
+        prefix: "978-80",
+        rules: &[
+            rule("0000000-1999999", 2),
+            rule("2000000-5299999", 3),
+            rule("5300000-5499999", 5),
+            rule("5500000-6899999", 3),
+            rule("6900000-6999999", 5),
+            rule("7000000-8499999", 4),
+            rule("8500000-8999999", 5),
+            rule("9000000-9989999", 6),
+            rule("9990000-9999999", 5),
+        ],
+    },
+    // India
+    Group {
+        prefix: "978-81",
+        rules: &[
+            rule("0000000-1899999", 2),
+            rule("1900000-1999999", 5),
+            rule("2000000-6999999", 3),
+            rule("7000000-8499999", 4),
+            rule("8500000-8999999", 5),
+            rule("9000000-9999999", 6),
+        ],
+    },
+    // Norway
+    Group {
+        prefix: "978-82",
+        rules: &[
+            rule("0000000-1999999", 2),
+            rule("2000000-6899999", 3),
+            rule("6900000-6999999", 6),
+            rule("7000000-8999999", 4),
+            rule("9000000-9899999", 5),
+            rule("9900000-9999999", 6),
+        ],
+    },
+    // Poland
+    Group {
+        prefix: "978-83",
+        rules: &[
+            rule("0000000-1999999", 2),
+            rule("2000000-5999999", 3),
+            rule("6000000-6999999", 5),
+            rule("7000000-8499999", 4),
+            rule("8500000-8999999", 5),
+            rule("9000000-9999999", 6),
+        ],
+    },
+    // Denmark
+    Group {
+        prefix: "978-87",
+        rules: &[
+            rule("0000000-2999999", 2),
+            rule("3000000-3999999", 0),
+            rule("4000000-6499999", 3),
+            rule("6500000-6999999", 0),
+            rule("7000000-7999999", 4),
+            rule("8000000-8499999", 0),
+            rule("8500000-9499999", 5),
+            rule("9500000-9699999", 0),
+            rule("9700000-9999999", 6),
+        ],
+    },
+    // Korea, Republic
+    Group {
+        prefix: "978-89",
+        rules: &[
+            rule("0000000-2499999", 2),
+            rule("2500000-5499999", 3),
+            rule("5500000-8499999", 4),
+            rule("8500000-9499999", 5),
+            rule("9500000-9699999", 6),
+            rule("9700000-9899999", 5),
+            rule("9900000-9999999", 3),
+        ],
+    },
+    // Netherlands
+    Group {
+        prefix: "978-90",
+        rules: &[
+            rule("0000000-1999999", 2),
+            rule("2000000-4999999", 3),
+            rule("5000000-6999999", 4),
+            rule("7000000-7999999", 5),
+            rule("8000000-8499999", 6),
+            rule("8500000-8999999", 4),
+            rule("9000000-9099999", 2),
+            rule("9100000-9399999", 6),
+            rule("9400000-9499999", 2),
+            rule("9500000-9999999", 6),
+        ],
+    },
+    // Sweden
+    Group {
+        prefix: "978-91",
+        rules: &[
+            rule("0000000-1999999", 1),
+            rule("2000000-4999999", 2),
+            rule("5000000-6499999", 3),
+            rule("6500000-6999999", 0),
+            rule("7000000-8199999", 4),
+            rule("8200000-8499999", 0),
+            rule("8500000-9499999", 5),
+            rule("9500000-9699999", 0),
+            rule("9700000-9999999", 6),
+        ],
+    },
+    // France
+    Group {
+        prefix: "979-10",
+        rules: &[
+            rule("0000000-1999999", 2),
+            rule("2000000-6999999", 3),
+            rule("7000000-8999999", 4),
+            rule("9000000-9759999", 5),
+            rule("9760000-9999999", 6),
+        ],
+    },
+    // Korea, Republic
+    Group {
+        prefix: "979-11",
+        rules: &[
+            rule("0000000-2499999", 2),
+            rule("2500000-5499999", 3),
+            rule("5500000-8499999", 4),
+            rule("8500000-9499999", 5),
+            rule("9500000-9999999", 6),
+        ],
+    },
+];
+// END GENERATED TABLES
+
+/// Finds the length of the next element after `prefix` for the digits which
+/// follow it, returning `None` if the prefix or range isn't defined.
+pub(crate) fn element_length(table: &[Group], prefix: &[i8], digits: &[i8]) -> Option<usize> {
+    let group = table.iter().find(|group| {
+        let mut group_digits = group.prefix.bytes().filter(|x| *x != b'-');
+
+        prefix
+            .iter()
+            .all(|digit| group_digits.next() == Some(b'0' + *digit as u8))
+            && group_digits.next().is_none()
+    })?;
+
+    // zero-padded ranges of equal length compare the same as their values
+    let mut value = [b'0'; 7];
+    for (slot, digit) in value.iter_mut().zip(digits) {
+        *slot = b'0' + *digit as u8;
+    }
+    let value = &value[..];
+
+    group
+        .rules
+        .iter()
+        .find(|rule| {
+            let (start, end) = (&rule.range[..7], &rule.range[8..]);
+            start.as_bytes() <= value && value <= end.as_bytes()
+        })
+        .map(|rule| rule.length)
+        .filter(|length| *length != 0)
+}
@@ -0,0 +1,153 @@
+//! ISMN validation in both its current 979-0 EAN-13 form and its legacy
+//! `M`-prefixed form.
+
+use crate::parse::read_digits;
+use crate::{validate_digits, Standard, Upc, UpcError};
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+/// Prefix of every ISMN in its EAN-13 form, which replaced the legacy `M`
+const ISMN_PREFIX: [i8; 4] = [9, 7, 9, 0];
+
+/// An [ISMN](https://en.wikipedia.org/wiki/International_Standard_Music_Number)
+/// for printed music, being an EAN-13 in the 979-0 prefix.
+///
+/// The legacy 10-character form replaces 979-0 with an `M` and has the same
+/// check digit, as `M` is weighted as a 3.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::Ismn;
+///
+/// let ismn: Ismn = "M-2306-7118-7".parse().unwrap();
+///
+/// assert_eq!(Ok(true), ismn.check());
+/// assert_eq!("9790230671187", ismn.to_string());
+/// assert_eq!("M230671187", format!("{:#}", ismn));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ismn {
+    /// 8 digits of the ISMN following the 979-0 prefix
+    pub ismn: [i8; 8],
+
+    /// Check digit for verification
+    pub check_digit: i8,
+}
+
+impl Ismn {
+    /// Creates a new [Ismn] from its 8 digits after the 979-0 prefix,
+    /// computing the check digit for it.
+    pub fn new(ismn: [i8; 8]) -> Result<Self, UpcError> {
+        validate_digits(&ismn)?;
+
+        let upc = Upc::new(Standard::Ean13(ismn_to_ean(&ismn)))?;
+
+        Ok(Self {
+            ismn,
+            check_digit: upc.check_digit,
+        })
+    }
+
+    /// Checks given ISMN passed
+    pub fn check(&self) -> Result<bool, UpcError> {
+        Upc::from(self.clone()).check()
+    }
+}
+
+impl From<Ismn> for Upc {
+    fn from(ismn: Ismn) -> Self {
+        Upc {
+            upc: Standard::Ean13(ismn_to_ean(&ismn.ismn)),
+            check_digit: ismn.check_digit,
+        }
+    }
+}
+
+impl TryFrom<Upc> for Ismn {
+    type Error = UpcError;
+
+    /// Converts a scanned EAN-13 to an [Ismn], failing with
+    /// [UpcError::InvalidPrefix] if it doesn't start with 979-0.
+    fn try_from(upc: Upc) -> Result<Self, Self::Error> {
+        match upc.to_ean13() {
+            Some(Upc {
+                upc: Standard::Ean13(ean),
+                check_digit,
+            }) if ean[..4] == ISMN_PREFIX => {
+                let mut ismn = [0; 8];
+                ismn.copy_from_slice(&ean[4..]);
+
+                Ok(Ismn { ismn, check_digit })
+            }
+            _ => Err(UpcError::InvalidPrefix),
+        }
+    }
+}
+
+impl FromStr for Ismn {
+    type Err = UpcError;
+
+    /// Parses an ISMN from either its 13 printed digits starting with 979-0
+    /// or its legacy form of an `M` followed by 9 digits. Whitespace and
+    /// hyphens are ignored as with [Upc].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim_start();
+
+        match s.strip_prefix(|c| c == 'M' || c == 'm') {
+            Some(rest) => {
+                let mut digits = [0; 9];
+
+                match read_digits(rest, &mut digits, false)? {
+                    9 => {
+                        let mut ismn = [0; 8];
+                        ismn.copy_from_slice(&digits[..8]);
+
+                        Ok(Ismn {
+                            ismn,
+                            check_digit: digits[8],
+                        })
+                    }
+                    len => Err(UpcError::InvalidLength(len + 1)),
+                }
+            }
+            None => match s.parse::<Upc>()? {
+                upc @ Upc {
+                    upc: Standard::Ean13(_),
+                    ..
+                } => Ismn::try_from(upc),
+                upc => Err(UpcError::InvalidLength(upc.upc.get_slice().len() + 1)),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Ismn {
+    /// Formats the 13 digits of this ISMN's EAN-13 form.
+    ///
+    /// The alternate `{:#}` flag formats the legacy form instead, such as
+    /// `M230671187`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("M")?;
+        } else {
+            f.write_str("9790")?;
+        }
+
+        for digit in self.ismn.iter() {
+            write!(f, "{}", digit)?;
+        }
+
+        write!(f, "{}", self.check_digit)
+    }
+}
+
+/// Prefixes the digits of an ISMN with 979-0 to give its EAN-13 payload.
+fn ismn_to_ean(ismn: &[i8; 8]) -> [i8; 12] {
+    let mut ean = [0; 12];
+    ean[..4].copy_from_slice(&ISMN_PREFIX);
+    ean[4..].copy_from_slice(ismn);
+
+    ean
+}
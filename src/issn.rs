@@ -0,0 +1,127 @@
+//! ISSN validation along with embedding into its 977-prefixed EAN-13 as
+//! printed on the covers of serials.
+
+use crate::parse::read_mod11_digits;
+use crate::{calculate_mod11_check_digit, validate_digits, Standard, Upc, UpcError};
+use core::fmt;
+use core::str::FromStr;
+
+/// An [ISSN](https://en.wikipedia.org/wiki/International_Standard_Serial_Number)
+/// using its modulo-11 check digit, where a check digit of `10` is printed as
+/// `X`.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::Issn;
+///
+/// let issn: Issn = "0317-8471".parse().unwrap();
+/// let ean = issn.to_ean13([0, 0]).unwrap();
+///
+/// assert_eq!(Ok(true), issn.check());
+/// assert_eq!("9770317847001", ean.to_string());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Issn {
+    /// First 7 digits of the ISSN
+    pub issn: [i8; 7],
+
+    /// Check digit for verification, from 0 to 10 (`X`)
+    pub check_digit: i8,
+}
+
+impl Issn {
+    /// Creates a new [Issn] from its first 7 digits, computing the check
+    /// digit for it.
+    pub fn new(issn: [i8; 7]) -> Result<Self, UpcError> {
+        validate_digits(&issn)?;
+
+        Ok(Self {
+            issn,
+            check_digit: calculate_mod11_check_digit(&issn),
+        })
+    }
+
+    /// Checks given ISSN passed
+    pub fn check(&self) -> Result<bool, UpcError> {
+        validate_digits(&self.issn)?;
+
+        if !(0..=10).contains(&self.check_digit) {
+            return Err(UpcError::CheckDigitOverflow {
+                found: self.check_digit,
+            });
+        }
+
+        Ok(calculate_mod11_check_digit(&self.issn) == self.check_digit)
+    }
+
+    /// Embeds this ISSN into its 977-prefixed EAN-13 with the given 2 variant
+    /// digits (usually `[0, 0]`, otherwise used for price or issue variants),
+    /// computing the EAN-13 check digit. The ISSN's own check digit isn't
+    /// carried over.
+    pub fn to_ean13(&self, variant: [i8; 2]) -> Result<Upc, UpcError> {
+        let mut ean = [9, 7, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        ean[3..10].copy_from_slice(&self.issn);
+        ean[10..].copy_from_slice(&variant);
+
+        Upc::new(Standard::Ean13(ean))
+    }
+
+    /// Extracts the ISSN and variant digits from a scanned 977-prefixed
+    /// EAN-13, computing the ISSN's check digit. Fails with
+    /// [UpcError::InvalidPrefix] for any other code.
+    pub fn from_ean13(upc: &Upc) -> Result<(Self, [i8; 2]), UpcError> {
+        match upc.to_ean13() {
+            Some(Upc {
+                upc: Standard::Ean13(ean),
+                ..
+            }) if ean[..3] == [9, 7, 7] => {
+                let mut issn = [0; 7];
+                issn.copy_from_slice(&ean[3..10]);
+
+                Ok((Issn::new(issn)?, [ean[10], ean[11]]))
+            }
+            _ => Err(UpcError::InvalidPrefix),
+        }
+    }
+}
+
+impl FromStr for Issn {
+    type Err = UpcError;
+
+    /// Parses an ISSN from its 8 printed digits, allowing a trailing `X`
+    /// check digit. Whitespace and hyphens are ignored as with [Upc].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = [0; 8];
+        read_mod11_digits(s, &mut digits)?;
+
+        let mut issn = [0; 7];
+        issn.copy_from_slice(&digits[..7]);
+
+        Ok(Issn {
+            issn,
+            check_digit: digits[7],
+        })
+    }
+}
+
+impl fmt::Display for Issn {
+    /// Formats the digits of this ISSN, with a check digit of 10 as `X`.
+    ///
+    /// The alternate `{:#}` flag splits the digits into two groups of 4 with
+    /// a hyphen, such as `0317-8471`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (ind, digit) in self.issn.iter().enumerate() {
+            if f.alternate() && ind == 4 {
+                f.write_str("-")?;
+            }
+
+            write!(f, "{}", digit)?;
+        }
+
+        match self.check_digit {
+            10 => f.write_str("X"),
+            check_digit => write!(f, "{}", check_digit),
+        }
+    }
+}
@@ -1,14 +1,57 @@
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
 
-/// Possible errors for [Upc]-based checkin
-#[derive(Debug, PartialEq, Clone)]
-pub enum UpcError {
-    /// Given i8 array has overflown with data that is not 0-9 (1 digit)
-    UpcOverflow,
+mod allocate;
+mod bookland;
+mod classify;
+mod company_prefix;
+mod coupon;
+mod display;
+mod error;
+mod explain;
+mod gdti;
+mod giai;
+mod gln;
+mod grai;
+mod gs1;
+mod gs1_prefixes;
+mod gsrn;
+mod isbn;
+mod isbn_ranges;
+mod ismn;
+mod issn;
+mod ndc;
+mod parse;
+mod recover;
+mod serial;
+mod sscc;
+mod suggest;
+mod supplement;
+mod variable;
 
-    /// Given i8 check digit has overflown with data that is not 0-9 (1 digit)
-    CheckDigitOverflow,
-}
+pub use allocate::CodeAllocator;
+pub use bookland::{BooklandPrice, Currency};
+pub use classify::Classification;
+pub use company_prefix::GtinParts;
+pub use coupon::{Coupon, CouponFormat};
+pub use error::UpcError;
+pub use explain::{CheckReport, Contribution};
+pub use gdti::Gdti;
+pub use giai::Giai;
+pub use gln::Gln;
+pub use grai::Grai;
+pub use gs1::{gs1_check, gs1_check_digit};
+pub use gsrn::Gsrn;
+pub use isbn::{HyphenatedIsbn, Isbn10, Isbn13};
+pub use ismn::Ismn;
+pub use issn::Issn;
+pub use ndc::{Ndc, NdcFormat};
+pub use serial::Serial;
+pub use sscc::{Sscc, SsccAllocator};
+pub use suggest::{Correction, Suggestion, Suggestions};
+pub use supplement::{Barcode, Parity, Supplement};
+pub use variable::{
+    price_check_digit_4, price_check_digit_5, MeasureFormat, MeasureLayout, VariableMeasure,
+};
 
 /// The implementation on the widely-used UPC code standards with simple `i8`
 /// arrays of a defined length.
@@ -16,14 +59,94 @@ pub enum UpcError {
 /// # Overflowing
 ///
 /// These arrays should **only** have int's that are 0-9 (1 digit) otherwise
-/// [Upc.check] will throw an error defined as [UpcError::UpcOverflow].
+/// [Upc::check] will throw an error defined as [UpcError::UpcOverflow] with
+/// the position of the offending digit.
 ///
 /// # Standards implemented
 ///
 /// - [Upc-A](https://en.wikipedia.org/wiki/Universal_Product_Code#Encoding)
+/// - [Ean-13](https://en.wikipedia.org/wiki/International_Article_Number)
+/// - [Ean-8](https://en.wikipedia.org/wiki/EAN-8)
+/// - [Upc-E](https://en.wikipedia.org/wiki/Universal_Product_Code#UPC-E),
+///   given as its number system (0 or 1) followed by the 6 compressed digits
+/// - [Gtin-14](https://en.wikipedia.org/wiki/Global_Trade_Item_Number), as
+///   printed in [ITF-14](https://en.wikipedia.org/wiki/ITF-14) barcodes,
+///   given as its packaging indicator followed by 12 digits
 #[derive(Debug, PartialEq, Clone)]
 pub enum Standard {
     UpcA([i8; 11]),
+    Ean13([i8; 12]),
+    Ean8([i8; 7]),
+    UpcE([i8; 7]),
+    Gtin14([i8; 13]),
+}
+
+impl Standard {
+    /// Computes the modulo-10 check digit for this code, allowing a complete
+    /// [Upc] to be minted from just the payload digits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::Standard;
+    ///
+    /// let code = Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]);
+    ///
+    /// assert_eq!(Ok(2), code.compute_check_digit());
+    /// ```
+    pub fn compute_check_digit(&self) -> Result<i8, UpcError> {
+        self.validate_overflow()?;
+
+        Ok(self.calculate_check_digit())
+    }
+
+    /// Calculates the check digit of this (already validated) code. UPC-E
+    /// codes are calculated from their expanded UPC-A form.
+    fn calculate_check_digit(&self) -> i8 {
+        self.with_check_slice(gs1::calculate_check_digit)
+    }
+
+    /// Runs `f` over the digits which the check digit is calculated from,
+    /// being the expanded UPC-A form for UPC-E codes.
+    fn with_check_slice<T>(&self, f: impl FnOnce(&[i8]) -> T) -> T {
+        match self {
+            Standard::UpcE(x) => f(&expand_upc_e(x)),
+            _ => f(self.get_slice()),
+        }
+    }
+
+    /// Converts any defined standards to an i8 slice and returns it.
+    fn get_slice(&self) -> &[i8] {
+        match self {
+            Standard::UpcA(x) => &x[..],
+            Standard::Ean13(x) => &x[..],
+            Standard::Ean8(x) => &x[..],
+            Standard::UpcE(x) => &x[..],
+            Standard::Gtin14(x) => &x[..],
+        }
+    }
+
+    /// Mutable version of [Standard::get_slice].
+    fn get_slice_mut(&mut self) -> &mut [i8] {
+        match self {
+            Standard::UpcA(x) => &mut x[..],
+            Standard::Ean13(x) => &mut x[..],
+            Standard::Ean8(x) => &mut x[..],
+            Standard::UpcE(x) => &mut x[..],
+            Standard::Gtin14(x) => &mut x[..],
+        }
+    }
+
+    /// Validates that none of the payload digits have overflown using the
+    /// `is_1_digit` helper function, alongside UPC-E's number system.
+    fn validate_overflow(&self) -> Result<(), UpcError> {
+        validate_digits(self.get_slice())?;
+
+        match self {
+            Standard::UpcE(x) if x[0] > 1 => Err(UpcError::InvalidNumberSystem { found: x[0] }),
+            _ => Ok(()),
+        }
+    }
 }
 
 /// Main Upc structure containing the base Upc code alonside it's
@@ -54,12 +177,10 @@ pub enum Standard {
 ///
 /// match code.check() {
 ///     Ok(x) => println!("Is the code valid?: {}", x),
-///     Err(UpcError::UpcOverflow) => {
-///         eprintln!("UPC code overflow! Please use only 0-9!");
-///     },
-///     Err(UpcError::CheckDigitOverflow) => {
-///         eprintln!("UPC check digit overflow! Please use only 0-9!");
+///     Err(UpcError::UpcOverflow { position, found }) => {
+///         eprintln!("UPC digit {} is {}! Please use only 0-9!", position, found);
 ///     },
+///     Err(err) => eprintln!("Invalid UPC: {}", err),
 /// };
 /// ```
 #[derive(Debug, PartialEq, Clone)]
@@ -72,62 +193,284 @@ pub struct Upc {
 }
 
 impl Upc {
+    /// Creates a new [Upc] from the given [Standard], computing the check
+    /// digit for it with [Standard::compute_check_digit].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::{Standard, Upc};
+    ///
+    /// let code = Upc::new(Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5])).unwrap();
+    ///
+    /// assert_eq!(2, code.check_digit);
+    /// assert_eq!(Ok(true), code.check());
+    /// ```
+    pub fn new(upc: Standard) -> Result<Self, UpcError> {
+        let check_digit = upc.compute_check_digit()?;
+
+        Ok(Self { upc, check_digit })
+    }
+
     /// Checks given upc code passed
     pub fn check(&self) -> Result<bool, UpcError> {
         self.validate_upc_overflow()?;
 
-        let (even_nums, odd_nums) = self.split_upc_even_odd();
+        Ok(self.upc.calculate_check_digit() == self.check_digit)
+    }
 
-        let total: u16 = ((odd_nums * 3) + even_nums) % 10;
+    /// Converts this code to its [Standard::Ean13] equivalent, returning
+    /// `None` if the code has no EAN-13 form.
+    ///
+    /// A UPC-A becomes an EAN-13 by prefixing it with a `0`, which keeps the
+    /// same check digit as the leading zero carries no weight. UPC-E codes are
+    /// expanded to UPC-A first.
+    pub fn to_ean13(&self) -> Option<Upc> {
+        match &self.upc {
+            Standard::UpcE(_) => self.to_upc_a()?.to_ean13(),
+            Standard::UpcA(x) => {
+                let mut ean = [0; 12];
+                ean[1..].copy_from_slice(x);
 
-        if (total == 0 && self.check_digit == 0) || (10 - total == self.check_digit as u16) {
-            return Ok(true);
-        } else {
-            return Ok(false);
+                Some(Upc {
+                    upc: Standard::Ean13(ean),
+                    check_digit: self.check_digit,
+                })
+            }
+            Standard::Ean13(_) => Some(self.clone()),
+            Standard::Gtin14(x) if x[0] == 0 => {
+                let mut ean = [0; 12];
+                ean.copy_from_slice(&x[1..]);
+
+                Some(Upc {
+                    upc: Standard::Ean13(ean),
+                    check_digit: self.check_digit,
+                })
+            }
+            Standard::Ean8(_) | Standard::Gtin14(_) => None,
         }
     }
 
-    /// Converts any defined standards given in [Standard] to an i8
-    /// slice and returns it.
-    fn get_upc_slice(&self) -> &[i8] {
+    /// Converts this code to its [Standard::UpcA] equivalent, returning
+    /// `None` if the code has no UPC-A form.
+    ///
+    /// An EAN-13 only has a UPC-A form if its leading digit is `0`, whereas a
+    /// UPC-E always has one by reversing its zero-suppression.
+    pub fn to_upc_a(&self) -> Option<Upc> {
         match &self.upc {
-            Standard::UpcA(x) => &x[..],
+            Standard::UpcA(_) => Some(self.clone()),
+            Standard::UpcE(x) => Some(Upc {
+                upc: Standard::UpcA(expand_upc_e(x)),
+                check_digit: self.check_digit,
+            }),
+            Standard::Ean13(x) if x[0] == 0 => {
+                let mut upc = [0; 11];
+                upc.copy_from_slice(&x[1..]);
+
+                Some(Upc {
+                    upc: Standard::UpcA(upc),
+                    check_digit: self.check_digit,
+                })
+            }
+            Standard::Ean13(_) | Standard::Ean8(_) => None,
+            Standard::Gtin14(_) => self.to_ean13()?.to_upc_a(),
+        }
+    }
+
+    /// Converts this code to its zero-suppressed [Standard::UpcE] equivalent,
+    /// returning `None` if the code has no UPC-E form.
+    ///
+    /// Only number system 0 and 1 UPC-A codes (or EAN-13 codes with a UPC-A
+    /// form) with enough zeros in the right places can be compressed, see
+    /// [Wikipedia](https://en.wikipedia.org/wiki/Universal_Product_Code#UPC-E)
+    /// for the rules used.
+    pub fn to_upc_e(&self) -> Option<Upc> {
+        match &self.upc {
+            Standard::UpcE(_) => Some(self.clone()),
+            Standard::UpcA(x) => Some(Upc {
+                upc: Standard::UpcE(compress_upc_a(x)?),
+                check_digit: self.check_digit,
+            }),
+            _ => self.to_upc_a()?.to_upc_e(),
+        }
+    }
+
+    /// Normalizes this code to its canonical 14-digit [Standard::Gtin14] form
+    /// by padding it with leading zeros, as used to key products by GTIN.
+    ///
+    /// UPC-E codes are expanded to UPC-A first and the check digit is always
+    /// kept as leading zeros carry no weight.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::{Standard, Upc};
+    ///
+    /// let code = Upc::new(Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5])).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Standard::Gtin14([0, 0, 0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]),
+    ///     code.to_gtin14().upc
+    /// );
+    /// ```
+    pub fn to_gtin14(&self) -> Upc {
+        Upc {
+            upc: Standard::Gtin14(self.gtin14_digits()),
+            check_digit: self.check_digit,
+        }
+    }
+
+    /// Creates the [Standard::Gtin14] for this code with the given packaging
+    /// indicator (1-8 for packaging levels, 9 for variable measure), computing
+    /// a new check digit for it.
+    pub fn with_packaging_indicator(&self, indicator: i8) -> Result<Upc, UpcError> {
+        let mut gtin = self.to_gtin14();
+
+        if let Standard::Gtin14(x) = &mut gtin.upc {
+            x[0] = indicator;
+        }
+
+        Upc::new(gtin.upc)
+    }
+
+    /// Gets the digit at `position` of the code, where the check digit comes
+    /// last.
+    fn get_digit(&self, position: usize) -> i8 {
+        *self
+            .upc
+            .get_slice()
+            .get(position)
+            .unwrap_or(&self.check_digit)
+    }
+
+    /// Sets the digit at `position` of the code, where the check digit comes
+    /// last.
+    fn set_digit(&mut self, position: usize, digit: i8) {
+        match self.upc.get_slice_mut().get_mut(position) {
+            Some(slot) => *slot = digit,
+            None => self.check_digit = digit,
+        }
+    }
+
+    /// Gets the digits of this code as a [Standard::Gtin14], without its
+    /// check digit.
+    fn gtin14_digits(&self) -> [i8; 13] {
+        let mut gtin = [0; 13];
+
+        match &self.upc {
+            Standard::UpcE(x) => gtin[2..].copy_from_slice(&expand_upc_e(x)),
+            other => {
+                let digits = other.get_slice();
+                gtin[13 - digits.len()..].copy_from_slice(digits);
+            }
         }
+
+        gtin
     }
 
     /// Validates that there has been no overflow of the [Upc] structure
     /// by hooking onto the `is_1_digit` helper function. This is the main
     /// source of the uses of [UpcError].
     fn validate_upc_overflow(&self) -> Result<(), UpcError> {
-        for code in self.get_upc_slice() {
-            is_1_digit(*code)?;
-        }
+        self.upc.validate_overflow()?;
 
-        is_1_digit(self.check_digit)
+        if is_1_digit(self.check_digit) {
+            Ok(())
+        } else {
+            Err(UpcError::CheckDigitOverflow {
+                found: self.check_digit,
+            })
+        }
     }
+}
 
-    /// Splits the UPC codes depending if they are odd or even (defined by a
-    /// mod) into one of 2 values in a tuple of `([EVEN] u16, [ODD] u16)`.
-    fn split_upc_even_odd(&self) -> (u16, u16) {
-        let mut even_odd: (u16, u16) = (0, 0);
+/// Calculates the modulo-11 check digit used by ISBN-10 and ISSN for the
+/// given (already validated) digits, weighting them from `digits.len() + 1`
+/// down to 2. A check digit of 10 is printed as `X`.
+fn calculate_mod11_check_digit(digits: &[i8]) -> i8 {
+    let sum: u16 = digits
+        .iter()
+        .enumerate()
+        .map(|(ind, digit)| *digit as u16 * (digits.len() + 1 - ind) as u16)
+        .sum();
 
-        for code in self.get_upc_slice() {
-            if code % 2 == 0 {
-                even_odd.0 += *code as u16;
-            } else {
-                even_odd.1 += *code as u16;
-            }
-        }
+    ((11 - sum % 11) % 11) as i8
+}
+
+/// Expands a UPC-E (number system and 6 digits) into its UPC-A payload
+/// depending on the last of the 6 digits.
+fn expand_upc_e(upc_e: &[i8; 7]) -> [i8; 11] {
+    let [ns, d1, d2, d3, d4, d5, d6] = *upc_e;
 
-        even_odd
+    match d6 {
+        0..=2 => [ns, d1, d2, d6, 0, 0, 0, 0, d3, d4, d5],
+        3 => [ns, d1, d2, d3, 0, 0, 0, 0, 0, d4, d5],
+        4 => [ns, d1, d2, d3, d4, 0, 0, 0, 0, 0, d5],
+        _ => [ns, d1, d2, d3, d4, d5, 0, 0, 0, 0, d6],
     }
 }
 
-/// Checks if a given i8 is 1 digit/character (0-9) wide
-fn is_1_digit(digit: i8) -> Result<(), UpcError> {
-    if digit < 0 || digit > 9 {
-        Err(UpcError::CheckDigitOverflow)
+/// Compresses a UPC-A payload into a UPC-E (number system and 6 digits) if
+/// its manufacturer (`m`) and product (`p`) codes allow it, the reverse of
+/// [expand_upc_e].
+fn compress_upc_a(upc_a: &[i8; 11]) -> Option<[i8; 7]> {
+    let [ns, m1, m2, m3, m4, m5, p1, p2, p3, p4, p5] = *upc_a;
+
+    if ns > 1 {
+        None
+    } else if m3 <= 2 && [m4, m5, p1, p2] == [0; 4] {
+        Some([ns, m1, m2, p3, p4, p5, m3])
+    } else if [m4, m5, p1, p2, p3] == [0; 5] {
+        Some([ns, m1, m2, m3, p4, p5, 3])
+    } else if [m5, p1, p2, p3, p4] == [0; 5] {
+        Some([ns, m1, m2, m3, m4, p5, 4])
+    } else if [p1, p2, p3, p4] == [0; 4] && p5 >= 5 {
+        Some([ns, m1, m2, m3, m4, m5, p5])
     } else {
+        None
+    }
+}
+
+/// Validates that every digit is 1 digit wide using [is_1_digit], giving the
+/// position of the first which isn't.
+fn validate_digits(digits: &[i8]) -> Result<(), UpcError> {
+    for (position, code) in digits.iter().enumerate() {
+        if !is_1_digit(*code) {
+            return Err(UpcError::UpcOverflow {
+                position,
+                found: *code,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the (already validated) digits as a single number.
+fn read_number(digits: &[i8]) -> u32 {
+    digits
+        .iter()
+        .fold(0, |number, digit| number * 10 + *digit as u32)
+}
+
+/// Writes a number into the digits, zero-padded on the left, failing if it
+/// doesn't fit.
+fn write_number(digits: &mut [i8], mut number: u64) -> Result<(), UpcError> {
+    let found = number;
+
+    for slot in digits.iter_mut().rev() {
+        *slot = (number % 10) as i8;
+        number /= 10;
+    }
+
+    if number == 0 {
         Ok(())
+    } else {
+        Err(UpcError::ValueTooLarge(found))
     }
 }
+
+/// Checks if a given i8 is 1 digit/character (0-9) wide
+fn is_1_digit(digit: i8) -> bool {
+    (0..=9).contains(&digit)
+}
@@ -0,0 +1,220 @@
+//! National Drug Codes as carried by UPC-A codes with number system 3, along
+//! with their 11-digit HIPAA form.
+
+use crate::parse::read_digits;
+use crate::{validate_digits, Standard, Upc, UpcError};
+use core::fmt;
+use core::str::FromStr;
+
+/// Segmentation of an [Ndc] into its labeler, product and package codes
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum NdcFormat {
+    /// 4-digit labeler, 4-digit product and 2-digit package codes
+    FourFourTwo,
+
+    /// 5-digit labeler, 3-digit product and 2-digit package codes
+    FiveThreeTwo,
+
+    /// 5-digit labeler, 4-digit product and 1-digit package codes
+    FiveFourOne,
+}
+
+impl NdcFormat {
+    /// Every format, in the order they're tried when parsing
+    const ALL: [NdcFormat; 3] = [
+        NdcFormat::FourFourTwo,
+        NdcFormat::FiveThreeTwo,
+        NdcFormat::FiveFourOne,
+    ];
+
+    /// Gets the lengths of the labeler, product and package codes.
+    pub fn segments(self) -> [usize; 3] {
+        match self {
+            NdcFormat::FourFourTwo => [4, 4, 2],
+            NdcFormat::FiveThreeTwo => [5, 3, 2],
+            NdcFormat::FiveFourOne => [5, 4, 1],
+        }
+    }
+
+    /// Gets the index in the HIPAA form of the leading zero which pads the
+    /// short segment to make 5-4-2.
+    fn hipaa_padding(self) -> usize {
+        match self {
+            NdcFormat::FourFourTwo => 0,
+            NdcFormat::FiveThreeTwo => 5,
+            NdcFormat::FiveFourOne => 9,
+        }
+    }
+}
+
+/// A 10-digit [National Drug Code](https://en.wikipedia.org/wiki/National_Drug_Code)
+/// along with how it's segmented, as the format can't be told from the
+/// digits alone.
+///
+/// Drug databases are usually keyed by the 11-digit HIPAA form from
+/// [Ndc::to_hipaa], which pads every format out to 5-4-2.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::{Ndc, NdcFormat, Upc};
+///
+/// let code: Upc = "350903001117".parse().unwrap();
+/// let ndc = Ndc::from_upc(&code, NdcFormat::FiveThreeTwo).unwrap();
+///
+/// assert_eq!("50903-001-11", format!("{:#}", ndc));
+/// assert_eq!([5, 0, 9, 0, 3, 0, 0, 0, 1, 1, 1], ndc.to_hipaa());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ndc {
+    /// All 10 digits of the NDC
+    pub ndc: [i8; 10],
+
+    /// How the digits are segmented
+    pub format: NdcFormat,
+}
+
+impl Ndc {
+    /// Extracts the NDC from a UPC-A with number system 3, being the 10
+    /// digits between the number system and check digit. Fails with
+    /// [UpcError::InvalidPrefix] for any other code.
+    pub fn from_upc(upc: &Upc, format: NdcFormat) -> Result<Self, UpcError> {
+        upc.validate_upc_overflow()?;
+
+        match upc.to_upc_a() {
+            Some(Upc {
+                upc: Standard::UpcA(x),
+                ..
+            }) if x[0] == 3 => {
+                let mut ndc = [0; 10];
+                ndc.copy_from_slice(&x[1..]);
+
+                Ok(Ndc { ndc, format })
+            }
+            _ => Err(UpcError::InvalidPrefix),
+        }
+    }
+
+    /// Generates the UPC-A for this NDC with number system 3, computing the
+    /// check digit.
+    pub fn to_upc(&self) -> Result<Upc, UpcError> {
+        let mut upc = [3; 11];
+        upc[1..].copy_from_slice(&self.ndc);
+
+        Upc::new(Standard::UpcA(upc))
+    }
+
+    /// Converts this NDC to its 11-digit HIPAA form, padding the short
+    /// segment with a leading zero to make 5-4-2.
+    pub fn to_hipaa(&self) -> [i8; 11] {
+        let padding = self.format.hipaa_padding();
+
+        let mut hipaa = [0; 11];
+        hipaa[..padding].copy_from_slice(&self.ndc[..padding]);
+        hipaa[padding + 1..].copy_from_slice(&self.ndc[padding..]);
+
+        hipaa
+    }
+
+    /// Converts an 11-digit HIPAA NDC back to the 10-digit NDC in the given
+    /// format, by removing the leading zero of the padded segment. Fails with
+    /// [UpcError::InvalidNdcFormat] if that segment doesn't start with a zero.
+    pub fn from_hipaa(hipaa: [i8; 11], format: NdcFormat) -> Result<Self, UpcError> {
+        validate_digits(&hipaa)?;
+
+        let padding = format.hipaa_padding();
+        if hipaa[padding] != 0 {
+            return Err(UpcError::InvalidNdcFormat);
+        }
+
+        let mut ndc = [0; 10];
+        ndc[..padding].copy_from_slice(&hipaa[..padding]);
+        ndc[padding..].copy_from_slice(&hipaa[padding + 1..]);
+
+        Ok(Ndc { ndc, format })
+    }
+
+    /// Converts this NDC to another format through its HIPAA form, which only
+    /// works when the segment padded by the other format starts with a zero.
+    pub fn to_format(&self, format: NdcFormat) -> Result<Self, UpcError> {
+        Ndc::from_hipaa(self.to_hipaa(), format)
+    }
+
+    /// Gets the labeler code, identifying the firm which makes or
+    /// distributes the drug.
+    pub fn labeler(&self) -> &[i8] {
+        let [labeler, _, _] = self.format.segments();
+
+        &self.ndc[..labeler]
+    }
+
+    /// Gets the product code, identifying the drug's strength, dosage form
+    /// and formulation.
+    pub fn product(&self) -> &[i8] {
+        let [labeler, product, _] = self.format.segments();
+
+        &self.ndc[labeler..labeler + product]
+    }
+
+    /// Gets the package code, identifying the package size and type.
+    pub fn package(&self) -> &[i8] {
+        let [labeler, product, _] = self.format.segments();
+
+        &self.ndc[labeler + product..]
+    }
+}
+
+impl FromStr for Ndc {
+    type Err = UpcError;
+
+    /// Parses a hyphenated NDC such as `50903-001-11`, detecting the
+    /// [NdcFormat] from the length of each segment. Whitespace is ignored as
+    /// with [Upc].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ndc = [0; 10];
+        match read_digits(s, &mut ndc, false)? {
+            10 => (),
+            len => return Err(UpcError::InvalidLength(len)),
+        }
+
+        let mut segments = [0; 3];
+        let mut parts = s.split('-');
+        for (segment, part) in segments.iter_mut().zip(&mut parts) {
+            *segment = part.chars().filter(char::is_ascii_digit).count();
+        }
+
+        if parts.next().is_some() {
+            return Err(UpcError::InvalidNdcFormat);
+        }
+
+        let format = NdcFormat::ALL
+            .iter()
+            .find(|format| format.segments() == segments)
+            .ok_or(UpcError::InvalidNdcFormat)?;
+
+        Ok(Ndc {
+            ndc,
+            format: *format,
+        })
+    }
+}
+
+impl fmt::Display for Ndc {
+    /// Formats the 10 digits of this NDC.
+    ///
+    /// The alternate `{:#}` flag separates the segments with hyphens, such
+    /// as `50903-001-11`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [labeler, product, _] = self.format.segments();
+
+        for (ind, digit) in self.ndc.iter().enumerate() {
+            if f.alternate() && (ind == labeler || ind == labeler + product) {
+                f.write_str("-")?;
+            }
+
+            write!(f, "{}", digit)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+//! String parsing for [Upc] codes as they are printed on labels or typed in
+//! by hand.
+
+use crate::{Standard, Upc, UpcError};
+use core::convert::TryFrom;
+use core::str::FromStr;
+
+/// Largest number of digits in any [Standard], including its check digit
+pub(crate) const MAX_DIGITS: usize = 14;
+
+/// Placeholder stored by [read_digits] for a `?` when unknown digits are
+/// allowed
+pub(crate) const UNKNOWN_DIGIT: i8 = -1;
+
+impl Upc {
+    /// Parses a UPC-E from its 8 printed digits (number system, 6 compressed
+    /// digits and check digit).
+    ///
+    /// This can't be detected by [Upc::from_str] as UPC-E and EAN-8 codes
+    /// are both 8 digits long, where [Standard::Ean8] is always picked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::{Standard, Upc};
+    ///
+    /// let code = Upc::parse_upc_e("0425 2614").unwrap();
+    ///
+    /// assert_eq!(Standard::UpcE([0, 4, 2, 5, 2, 6, 1]), code.upc);
+    /// assert_eq!(4, code.check_digit);
+    /// ```
+    pub fn parse_upc_e(s: &str) -> Result<Self, UpcError> {
+        let mut digits = [0; MAX_DIGITS];
+        let len = read_digits(s, &mut digits, false)?;
+
+        from_upc_e_digits(&digits[..len])
+    }
+}
+
+impl FromStr for Upc {
+    type Err = UpcError;
+
+    /// Parses a code from its printed digits, detecting the [Standard] from
+    /// the number of digits found: 8 for EAN-8, 12 for UPC-A, 13 for EAN-13
+    /// and 14 for GTIN-14. Surrounding whitespace along with any spaces or
+    /// hyphens between digits are ignored.
+    ///
+    /// The check digit is taken as-is, so use [Upc::check] to verify it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::{Standard, Upc};
+    ///
+    /// let code: Upc = " 0 36000-29145 2 ".parse().unwrap();
+    ///
+    /// assert_eq!(Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]), code.upc);
+    /// assert_eq!(Ok(true), code.check());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = [0; MAX_DIGITS];
+        let len = read_digits(s, &mut digits, false)?;
+
+        from_digits(&digits[..len])
+    }
+}
+
+impl TryFrom<&str> for Upc {
+    type Error = UpcError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Creates a code from all of its digits, detecting the [Standard] from how
+/// many there are.
+pub(crate) fn from_digits(digits: &[i8]) -> Result<Upc, UpcError> {
+    let upc = match digits.len() {
+        8 => Standard::Ean8([0; 7]),
+        12 => Standard::UpcA([0; 11]),
+        13 => Standard::Ean13([0; 12]),
+        14 => Standard::Gtin14([0; 13]),
+        len => return Err(UpcError::InvalidLength(len)),
+    };
+
+    let (check_digit, payload) = digits.split_last().unwrap();
+    let mut upc = Upc {
+        upc,
+        check_digit: *check_digit,
+    };
+    upc.upc.get_slice_mut().copy_from_slice(payload);
+
+    Ok(upc)
+}
+
+/// Creates a UPC-E from all 8 of its digits.
+pub(crate) fn from_upc_e_digits(digits: &[i8]) -> Result<Upc, UpcError> {
+    match digits.len() {
+        8 => {
+            let mut upc = [0; 7];
+            upc.copy_from_slice(&digits[..7]);
+
+            Ok(Upc {
+                upc: Standard::UpcE(upc),
+                check_digit: digits[7],
+            })
+        }
+        len => Err(UpcError::InvalidLength(len)),
+    }
+}
+
+/// Reads exactly `digits.len()` digits from a printed modulo-11 code, where
+/// the last (check) digit may be an `X` which is read as 10.
+pub(crate) fn read_mod11_digits(s: &str, digits: &mut [i8]) -> Result<(), UpcError> {
+    let s = s.trim_end();
+    let (s, check_x) = match s.strip_suffix(|c| c == 'X' || c == 'x') {
+        Some(s) => (s, true),
+        None => (s, false),
+    };
+
+    let len = read_digits(s, digits, false)?;
+
+    match (digits.len() - len, check_x) {
+        (0, false) => Ok(()),
+        (1, true) => {
+            digits[len] = 10;
+            Ok(())
+        }
+        _ => Err(UpcError::InvalidLength(len + check_x as usize)),
+    }
+}
+
+/// Reads the digits from a printed code into `digits`, returning how many were
+/// found. Whitespace and hyphens are skipped wherever they are in the string,
+/// and a `?` is read as [UNKNOWN_DIGIT] if `allow_unknown` is set.
+pub(crate) fn read_digits(
+    s: &str,
+    digits: &mut [i8],
+    allow_unknown: bool,
+) -> Result<usize, UpcError> {
+    let mut len = 0;
+
+    for (position, found) in s.chars().enumerate() {
+        let digit = match found.to_digit(10) {
+            Some(digit) => digit as i8,
+            None if allow_unknown && found == '?' => UNKNOWN_DIGIT,
+            None if found.is_whitespace() || found == '-' => continue,
+            None => return Err(UpcError::InvalidCharacter { position, found }),
+        };
+
+        if let Some(slot) = digits.get_mut(len) {
+            *slot = digit;
+        }
+
+        len += 1;
+    }
+
+    if len > digits.len() {
+        Err(UpcError::InvalidLength(len))
+    } else {
+        Ok(len)
+    }
+}
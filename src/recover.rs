@@ -0,0 +1,74 @@
+//! Reconstruction of a single unknown digit, such as one smudged on a
+//! damaged label, by solving the check digit equation.
+
+use crate::parse::{from_digits, from_upc_e_digits, read_digits, MAX_DIGITS, UNKNOWN_DIGIT};
+use crate::{Upc, UpcError};
+
+impl Upc {
+    /// Recovers a code from its printed digits with exactly one digit, which
+    /// may be the check digit, replaced by a `?`. The [Standard](crate::Standard)
+    /// is detected in the same way as [Upc::from_str](core::str::FromStr).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::Upc;
+    ///
+    /// let code = Upc::recover("03600?241457").unwrap();
+    ///
+    /// assert_eq!("036000241457", code.to_string());
+    /// ```
+    pub fn recover(s: &str) -> Result<Self, UpcError> {
+        let mut digits = [0; MAX_DIGITS];
+        let len = read_digits(s, &mut digits, true)?;
+
+        solve(from_digits(&digits[..len])?)
+    }
+
+    /// Recovers a UPC-E in the same way as [Upc::recover], which can't be
+    /// detected for the same reason as with [Upc::parse_upc_e].
+    ///
+    /// Unlike the other standards, a UPC-E can be left with more than one
+    /// solution as its last digit changes how it's expanded, in which case
+    /// [UpcError::AmbiguousDigit] is returned.
+    pub fn recover_upc_e(s: &str) -> Result<Self, UpcError> {
+        let mut digits = [0; MAX_DIGITS];
+        let len = read_digits(s, &mut digits, true)?;
+
+        solve(from_upc_e_digits(&digits[..len])?)
+    }
+}
+
+/// Finds the only digit in place of the [UNKNOWN_DIGIT] in `upc` which makes
+/// it pass [Upc::check].
+fn solve(mut upc: Upc) -> Result<Upc, UpcError> {
+    let len = upc.upc.get_slice().len() + 1;
+    let is_unknown = |ind: &usize| upc.get_digit(*ind) == UNKNOWN_DIGIT;
+
+    let position = match (0..len).filter(is_unknown).count() {
+        1 => (0..len).find(is_unknown).unwrap(),
+        count => return Err(UpcError::UnknownDigits(count)),
+    };
+
+    let mut solution = None;
+
+    for digit in 0..=9 {
+        upc.set_digit(position, digit);
+
+        if upc.check() == Ok(true) {
+            if solution.is_some() {
+                return Err(UpcError::AmbiguousDigit { position });
+            }
+
+            solution = Some(digit);
+        }
+    }
+
+    match solution {
+        Some(digit) => {
+            upc.set_digit(position, digit);
+            Ok(upc)
+        }
+        None => Err(UpcError::UnrecoverableDigit { position }),
+    }
+}
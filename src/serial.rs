@@ -0,0 +1,122 @@
+//! Alphanumeric serial components of GS1 keys such as GRAIs and GIAIs, along
+//! with parsing helpers for keys printed as GS1 element strings.
+
+use crate::UpcError;
+use core::fmt;
+use core::str::FromStr;
+
+/// Longest serial component of any GS1 key, being a GIAI
+const MAX_LEN: usize = 30;
+
+/// Alphanumeric serial component of a GS1 key, made up of 1 to 30 characters
+/// from GS1's character set 82 (digits, letters and `!"%&'()*+,-./:;<=>?_`)
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::Serial;
+///
+/// let serial: Serial = "ABC-123".parse().unwrap();
+///
+/// assert_eq!("ABC-123", serial.as_str());
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Serial {
+    chars: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl Serial {
+    /// Parses a serial of at most `max_len` characters.
+    pub(crate) fn parse(s: &str, max_len: usize) -> Result<Self, UpcError> {
+        let mut serial = Serial {
+            chars: [0; MAX_LEN],
+            len: 0,
+        };
+
+        for (position, found) in s.chars().enumerate() {
+            if !is_cset82(found) {
+                return Err(UpcError::InvalidCharacter { position, found });
+            }
+
+            if let Some(slot) = serial.chars.get_mut(position) {
+                *slot = found as u8;
+            }
+
+            serial.len += 1;
+        }
+
+        serial.validate(max_len)?;
+
+        Ok(serial)
+    }
+
+    /// Checks this serial is no longer than `max_len` characters, which
+    /// depends on the key it's part of.
+    pub(crate) fn validate(&self, max_len: usize) -> Result<(), UpcError> {
+        if (1..=max_len).contains(&self.len) {
+            Ok(())
+        } else {
+            Err(UpcError::InvalidSerialLength {
+                max: max_len,
+                found: self.len,
+            })
+        }
+    }
+
+    /// Gets the characters of this serial.
+    pub fn as_str(&self) -> &str {
+        // only ASCII characters are ever stored
+        core::str::from_utf8(&self.chars[..self.len]).unwrap()
+    }
+}
+
+impl FromStr for Serial {
+    type Err = UpcError;
+
+    /// Parses a serial of 1 to 30 characters, failing with
+    /// [UpcError::InvalidCharacter] for any character outside of GS1's
+    /// character set 82.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Serial::parse(s, MAX_LEN)
+    }
+}
+
+impl fmt::Display for Serial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Checks if a character is in GS1's character set 82, which is allowed in
+/// alphanumeric GS1 keys.
+fn is_cset82(found: char) -> bool {
+    matches!(found, '!' | '"' | '%'..='?' | 'A'..='Z' | '_' | 'a'..='z')
+}
+
+/// Strips the surrounding whitespace and an optional application identifier
+/// such as `(00)` from a printed GS1 element string.
+pub(crate) fn strip_ai<'a>(s: &'a str, ai: &str) -> &'a str {
+    let s = s.trim();
+
+    s.strip_prefix(ai).unwrap_or(s).trim_start()
+}
+
+/// Reads exactly `digits.len()` digits from the start of a GS1 element
+/// string, returning the rest of the string with surrounding whitespace
+/// removed.
+pub(crate) fn read_key_digits<'a>(s: &'a str, digits: &mut [i8]) -> Result<&'a str, UpcError> {
+    let mut chars = s.chars();
+
+    for (position, slot) in digits.iter_mut().enumerate() {
+        match chars.next() {
+            Some(found) => match found.to_digit(10) {
+                Some(digit) => *slot = digit as i8,
+                None => return Err(UpcError::InvalidCharacter { position, found }),
+            },
+            None => return Err(UpcError::InvalidLength(position)),
+        }
+    }
+
+    Ok(chars.as_str().trim())
+}
@@ -0,0 +1,210 @@
+//! SSCC-18 codes identifying logistic units such as pallets and cartons.
+
+use crate::parse::read_digits;
+use crate::serial::strip_ai;
+use crate::{gs1_check, gs1_check_digit, write_number, UpcError};
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+/// GS1 application identifier printed before an SSCC in a GS1-128 barcode
+const APPLICATION_IDENTIFIER: &str = "(00)";
+
+/// An [SSCC](https://www.gs1.org/standards/id-keys/sscc) (Serial Shipping
+/// Container Code), made up of an extension digit, GS1 company prefix and
+/// serial reference followed by a check digit using the same modulo-10
+/// weighting as [Upc](crate::Upc).
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::Sscc;
+///
+/// let sscc: Sscc = "(00) 106141411234567897".parse().unwrap();
+///
+/// assert_eq!(Ok(true), sscc.check());
+/// assert_eq!("(00)106141411234567897", format!("{:#}", sscc));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Sscc {
+    /// First 17 digits of the SSCC
+    pub sscc: [i8; 17],
+
+    /// Check digit for verification
+    pub check_digit: i8,
+}
+
+impl Sscc {
+    /// Creates a new [Sscc] from its first 17 digits, computing the check
+    /// digit for it.
+    pub fn new(sscc: [i8; 17]) -> Result<Self, UpcError> {
+        Ok(Self {
+            sscc,
+            check_digit: gs1_check_digit(&sscc)?,
+        })
+    }
+
+    /// Creates a new [Sscc] from its extension digit, company prefix in its
+    /// GTIN-13 form (4 to 12 digits) and serial reference, computing the
+    /// check digit for it.
+    ///
+    /// Fails with [UpcError::InvalidPrefixLength] for a company prefix
+    /// outside of 4 to 12 digits, or [UpcError::ValueTooLarge] if the serial
+    /// reference doesn't fit in the digits left after the company prefix.
+    pub fn from_parts(extension: i8, company_prefix: &[i8], serial: u64) -> Result<Self, UpcError> {
+        let prefix_len = company_prefix.len();
+        if !(4..=12).contains(&prefix_len) {
+            return Err(UpcError::InvalidPrefixLength(prefix_len));
+        }
+
+        let mut sscc = [0; 17];
+        sscc[0] = extension;
+        sscc[1..1 + prefix_len].copy_from_slice(company_prefix);
+        write_number(&mut sscc[1 + prefix_len..], serial)?;
+
+        Sscc::new(sscc)
+    }
+
+    /// Checks given SSCC passed
+    pub fn check(&self) -> Result<bool, UpcError> {
+        gs1_check(&self.sscc, self.check_digit)
+    }
+
+    /// Extension digit, used by the company to increase the number of serial
+    /// references available
+    pub fn extension(&self) -> i8 {
+        self.sscc[0]
+    }
+}
+
+impl FromStr for Sscc {
+    type Err = UpcError;
+
+    /// Parses an SSCC from its 18 printed digits, optionally preceded by its
+    /// `(00)` application identifier. Whitespace and hyphens are ignored as
+    /// with [Upc](crate::Upc).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = strip_ai(s, APPLICATION_IDENTIFIER);
+
+        let mut digits = [0; 18];
+        match read_digits(s, &mut digits, false)? {
+            18 => (),
+            len => return Err(UpcError::InvalidLength(len)),
+        }
+
+        let mut sscc = [0; 17];
+        sscc.copy_from_slice(&digits[..17]);
+
+        Ok(Sscc {
+            sscc,
+            check_digit: digits[17],
+        })
+    }
+}
+
+impl fmt::Display for Sscc {
+    /// Formats the 18 digits of this SSCC.
+    ///
+    /// The alternate `{:#}` flag adds the `(00)` application identifier in
+    /// front, as printed under a GS1-128 barcode.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(APPLICATION_IDENTIFIER)?;
+        }
+
+        for digit in self.sscc.iter() {
+            write!(f, "{}", digit)?;
+        }
+
+        write!(f, "{}", self.check_digit)
+    }
+}
+
+/// Iterator yielding SSCCs with consecutive serial references, created by
+/// [SsccAllocator::new]
+///
+/// Iteration stops once every serial reference has been used, where
+/// [SsccAllocator::next_sscc] instead fails with
+/// [UpcError::CapacityExhausted].
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::SsccAllocator;
+///
+/// let mut allocator = SsccAllocator::new(0, &[0, 6, 1, 4, 1, 4, 1], 0).unwrap();
+///
+/// assert_eq!("006141410000000005", allocator.next_sscc().unwrap().to_string());
+/// assert_eq!("006141410000000012", allocator.next_sscc().unwrap().to_string());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SsccAllocator {
+    extension: i8,
+    company_prefix: [i8; 12],
+    prefix_len: usize,
+    next_serial: u64,
+    capacity: u64,
+}
+
+impl SsccAllocator {
+    /// Creates an allocator for an extension digit and company prefix in its
+    /// GTIN-13 form (4 to 12 digits), starting from the given serial
+    /// reference.
+    ///
+    /// Fails in the same cases as [Sscc::from_parts].
+    pub fn new(extension: i8, company_prefix: &[i8], start: u64) -> Result<Self, UpcError> {
+        Sscc::from_parts(extension, company_prefix, start)?;
+
+        let prefix_len = company_prefix.len();
+        let mut prefix = [0; 12];
+        prefix[..prefix_len].copy_from_slice(company_prefix);
+
+        Ok(Self {
+            extension,
+            company_prefix: prefix,
+            prefix_len,
+            next_serial: start,
+            capacity: 10u64.pow((16 - prefix_len) as u32),
+        })
+    }
+
+    /// Gets how many serial references are left to be allocated.
+    pub fn remaining(&self) -> u64 {
+        self.capacity - self.next_serial
+    }
+
+    /// Allocates the SSCC for the next serial reference, computing its check
+    /// digit. Fails with [UpcError::CapacityExhausted] once every serial
+    /// reference has been used.
+    pub fn next_sscc(&mut self) -> Result<Sscc, UpcError> {
+        if self.next_serial >= self.capacity {
+            return Err(UpcError::CapacityExhausted);
+        }
+
+        let sscc = Sscc::from_parts(
+            self.extension,
+            &self.company_prefix[..self.prefix_len],
+            self.next_serial,
+        )?;
+        self.next_serial += 1;
+
+        Ok(sscc)
+    }
+}
+
+impl Iterator for SsccAllocator {
+    type Item = Sscc;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_sscc().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // up to 10^12 serial references can be left, which doesn't fit in a
+        // 32-bit usize
+        match usize::try_from(self.remaining()) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
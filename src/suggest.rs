@@ -0,0 +1,197 @@
+//! Suggestions of corrections for mistyped [Upc] codes, found by searching
+//! for the single substitutions and adjacent transpositions which pass
+//! [Upc::check].
+
+use crate::Upc;
+
+/// Single change made to a code by a [Suggestion]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Correction {
+    /// Digit at `position` was substituted from `from` to `to`
+    Substitution {
+        /// Index of the digit in the code, counting from 0
+        position: usize,
+
+        /// Original digit
+        from: i8,
+
+        /// Suggested digit
+        to: i8,
+    },
+
+    /// Digits at `position` and `position + 1` were swapped
+    Transposition {
+        /// Index of the first digit in the code, counting from 0
+        position: usize,
+    },
+}
+
+/// Candidate correction for an invalid code, yielded by [Suggestions]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Suggestion {
+    /// Corrected code, which passes [Upc::check]
+    pub upc: Upc,
+
+    /// Change made to the original code
+    pub correction: Correction,
+}
+
+/// Iterator over every [Suggestion] for a code, created by [Upc::suggestions]
+#[derive(Debug, Clone)]
+pub struct Suggestions {
+    upc: Upc,
+    len: usize,
+    tier: Tier,
+    next_ind: usize,
+}
+
+/// Likelihood tier of a candidate, yielded in order of declaration
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Tier {
+    /// Substitution between neighbouring keys
+    AdjacentKey,
+
+    /// Transposition of two adjacent digits
+    Transposition,
+
+    /// Substitution between any other keys
+    OtherKey,
+}
+
+impl Upc {
+    /// Suggests corrections which make this code valid, covering every single
+    /// substituted digit and every adjacent transposition, with positions
+    /// counting the check digit as the last digit. Codes which make
+    /// [Upc::check] fail with an error, such as those with digits outside of
+    /// 0 to 9, have no suggestions.
+    ///
+    /// Any invalid code has exactly one valid substitution at every position,
+    /// other than the digit before a UPC-E's check digit which changes how
+    /// the code is expanded, so may have none or several. Suggestions are
+    /// therefore ranked by how likely each mistake is when typing codes by
+    /// hand, in three tiers which each run from left to right:
+    ///
+    /// 1. Substitutions between neighbouring keys, on either the top row of a
+    ///    keyboard or a numeric keypad, which are the most common slips
+    /// 2. Transpositions, which only make a code valid for a few digit pairs
+    ///    so are a strong signal when they do
+    /// 3. Every other substitution
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::{Correction, Upc};
+    ///
+    /// let code: Upc = "036000421457".parse().unwrap();
+    ///
+    /// assert!(code
+    ///     .suggestions()
+    ///     .any(|x| x.correction == Correction::Transposition { position: 6 }));
+    /// ```
+    pub fn suggestions(&self) -> Suggestions {
+        let (tier, next_ind) = match self.validate_upc_overflow() {
+            Ok(()) => (Tier::AdjacentKey, 0),
+            // start past the final candidate of the final tier
+            Err(_) => (Tier::OtherKey, usize::MAX),
+        };
+
+        Suggestions {
+            upc: self.clone(),
+            len: self.upc.get_slice().len() + 1,
+            tier,
+            next_ind,
+        }
+    }
+}
+
+impl Suggestions {
+    /// Creates the candidate at `ind` if it's in the current tier, where the
+    /// first `len * 10` are each position's substitutions and the rest are
+    /// transpositions.
+    fn candidate(&self, ind: usize) -> Option<Suggestion> {
+        let mut upc = self.upc.clone();
+
+        let correction = if ind < self.len * 10 {
+            let position = ind / 10;
+            let from = upc.get_digit(position);
+            let to = (ind % 10) as i8;
+
+            let tier = if is_adjacent_key(from, to) {
+                Tier::AdjacentKey
+            } else {
+                Tier::OtherKey
+            };
+            if from == to || tier != self.tier {
+                return None;
+            }
+
+            upc.set_digit(position, to);
+            Correction::Substitution { position, from, to }
+        } else {
+            let position = ind - self.len * 10;
+            let (left, right) = (upc.get_digit(position), upc.get_digit(position + 1));
+
+            if left == right || self.tier != Tier::Transposition {
+                return None;
+            }
+
+            upc.set_digit(position, right);
+            upc.set_digit(position + 1, left);
+            Correction::Transposition { position }
+        };
+
+        match upc.check() {
+            Ok(true) => Some(Suggestion { upc, correction }),
+            _ => None,
+        }
+    }
+}
+
+impl Iterator for Suggestions {
+    type Item = Suggestion;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let total = self.len * 10 + self.len - 1;
+
+        loop {
+            while self.next_ind < total {
+                let ind = self.next_ind;
+                self.next_ind += 1;
+
+                if let Some(suggestion) = self.candidate(ind) {
+                    return Some(suggestion);
+                }
+            }
+
+            self.tier = match self.tier {
+                Tier::AdjacentKey => Tier::Transposition,
+                Tier::Transposition => Tier::OtherKey,
+                Tier::OtherKey => return None,
+            };
+            self.next_ind = 0;
+        }
+    }
+}
+
+/// Checks if two digits are neighbouring keys on either the top row of a
+/// keyboard (`1234567890`) or a numeric keypad, where the wide `0` key sits
+/// below both `1` and `2`.
+fn is_adjacent_key(a: i8, b: i8) -> bool {
+    // index along the top row, where 0 comes after 9
+    let row_index = |digit: i8| (digit + 9) % 10;
+
+    // (row, column) on the keypad, with 7 8 9 at the top
+    let keypad = |digit: i8| match digit {
+        0 => (3, 0),
+        _ => (2 - (digit - 1) / 3, (digit - 1) % 3),
+    };
+
+    let (a_row, a_col) = keypad(a);
+    let (b_row, b_col) = keypad(b);
+    let keypad_adjacent = match (a, b) {
+        (0, 2) | (2, 0) => true,
+        _ => (a_row - b_row).abs() + (a_col - b_col).abs() == 1,
+    };
+
+    (row_index(a) - row_index(b)).abs() == 1 || keypad_adjacent
+}
@@ -0,0 +1,205 @@
+//! EAN-2 and EAN-5 supplemental add-on codes, printed to the right of a
+//! UPC-A or EAN-13 for periodical issue numbers and book prices.
+
+use crate::parse::read_digits;
+use crate::{validate_digits, Upc, UpcError};
+use core::fmt;
+use core::str::FromStr;
+
+/// Parity of a digit's bar pattern within a [Supplement], which is how an
+/// add-on encodes its check instead of with a check digit
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Parity {
+    /// Odd parity, written as `L` in the EAN specification
+    Odd,
+
+    /// Even parity, written as `G` in the EAN specification
+    Even,
+}
+
+use Parity::{Even as G, Odd as L};
+
+/// EAN-2 parity patterns for each check value (the 2-digit value modulo 4)
+const EAN2_PATTERNS: [[Parity; 2]; 4] = [[L, L], [L, G], [G, L], [G, G]];
+
+/// EAN-5 parity patterns for each check value
+const EAN5_PATTERNS: [[Parity; 5]; 10] = [
+    [G, G, L, L, L],
+    [G, L, G, L, L],
+    [G, L, L, G, L],
+    [G, L, L, L, G],
+    [L, G, G, L, L],
+    [L, L, G, G, L],
+    [L, L, L, G, G],
+    [L, G, L, G, L],
+    [L, G, L, L, G],
+    [L, L, G, L, G],
+];
+
+/// The implementation of the [EAN-2](https://en.wikipedia.org/wiki/EAN-2) and
+/// [EAN-5](https://en.wikipedia.org/wiki/EAN-5) supplemental add-on codes.
+///
+/// Add-ons have no check digit, instead a check value calculated from their
+/// digits selects the [Parity] of each digit's bars. Scanners verify this
+/// pattern, which can be done with [Supplement::check].
+#[derive(Debug, PartialEq, Clone)]
+pub enum Supplement {
+    Ean2([i8; 2]),
+    Ean5([i8; 5]),
+}
+
+impl Supplement {
+    /// Calculates the check value selecting this add-on's parity pattern,
+    /// being the 2-digit value modulo 4 for EAN-2 and the digits weighted
+    /// 3, 9, 3, 9, 3 modulo 10 for EAN-5.
+    pub fn check_value(&self) -> Result<i8, UpcError> {
+        validate_digits(self.get_slice())?;
+
+        Ok(match self {
+            Supplement::Ean2([a, b]) => (a * 10 + b) % 4,
+            Supplement::Ean5(x) => {
+                let sum: u16 = x
+                    .iter()
+                    .enumerate()
+                    .map(|(ind, digit)| *digit as u16 * if ind % 2 == 0 { 3 } else { 9 })
+                    .sum();
+
+                (sum % 10) as i8
+            }
+        })
+    }
+
+    /// Gets the parity pattern which this add-on's digits are encoded with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::{Parity, Supplement};
+    ///
+    /// let supplement = Supplement::Ean5([5, 2, 4, 9, 5]);
+    ///
+    /// assert_eq!(
+    ///     Ok(&[Parity::Even, Parity::Odd, Parity::Even, Parity::Odd, Parity::Odd][..]),
+    ///     supplement.parity_pattern()
+    /// );
+    /// ```
+    pub fn parity_pattern(&self) -> Result<&'static [Parity], UpcError> {
+        let check_value = self.check_value()? as usize;
+
+        Ok(match self {
+            Supplement::Ean2(_) => &EAN2_PATTERNS[check_value],
+            Supplement::Ean5(_) => &EAN5_PATTERNS[check_value],
+        })
+    }
+
+    /// Checks that the parity pattern scanned from this add-on's bars matches
+    /// the one expected for its digits.
+    pub fn check(&self, scanned: &[Parity]) -> Result<bool, UpcError> {
+        Ok(self.parity_pattern()? == scanned)
+    }
+
+    /// Converts any defined supplement to an i8 slice and returns it.
+    fn get_slice(&self) -> &[i8] {
+        match self {
+            Supplement::Ean2(x) => &x[..],
+            Supplement::Ean5(x) => &x[..],
+        }
+    }
+}
+
+/// Main [Upc] code alongside the [Supplement] add-on printed next to it, if
+/// any.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Barcode {
+    /// Main code
+    pub upc: Upc,
+
+    /// Add-on code, if one was printed
+    pub supplement: Option<Supplement>,
+}
+
+impl Barcode {
+    /// Checks the main code with [Upc::check], also making sure any add-on
+    /// only contains digits.
+    pub fn check(&self) -> Result<bool, UpcError> {
+        if let Some(supplement) = &self.supplement {
+            validate_digits(supplement.get_slice())?;
+        }
+
+        self.upc.check()
+    }
+}
+
+impl FromStr for Supplement {
+    type Err = UpcError;
+
+    /// Parses an add-on from its 2 or 5 printed digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut digits = [0; 5];
+
+        match read_digits(s, &mut digits, false)? {
+            2 => Ok(Supplement::Ean2([digits[0], digits[1]])),
+            5 => Ok(Supplement::Ean5(digits)),
+            len => Err(UpcError::InvalidLength(len)),
+        }
+    }
+}
+
+impl FromStr for Barcode {
+    type Err = UpcError;
+
+    /// Parses a main code as with [Upc::from_str](core::str::FromStr),
+    /// optionally followed by whitespace and a 2 or 5 digit add-on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use upc_checker::{Barcode, Supplement};
+    ///
+    /// let barcode: Barcode = "978-0-306-40615-7 51299".parse().unwrap();
+    ///
+    /// assert_eq!(Some(Supplement::Ean5([5, 1, 2, 9, 9])), barcode.supplement);
+    /// assert_eq!(Ok(true), barcode.check());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some((upc, supplement)) = s.rsplit_once(char::is_whitespace) {
+            if let (Ok(upc), Ok(supplement)) = (upc.parse(), supplement.parse()) {
+                return Ok(Barcode {
+                    upc,
+                    supplement: Some(supplement),
+                });
+            }
+        }
+
+        Ok(Barcode {
+            upc: s.parse()?,
+            supplement: None,
+        })
+    }
+}
+
+impl fmt::Display for Supplement {
+    /// Formats the digits of this add-on.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for digit in self.get_slice() {
+            write!(f, "{}", digit)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Barcode {
+    /// Formats the main code followed by a space and any add-on, passing the
+    /// alternate `{:#}` flag through to the main code.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.upc, f)?;
+
+        match &self.supplement {
+            Some(supplement) => write!(f, " {}", supplement),
+            None => Ok(()),
+        }
+    }
+}
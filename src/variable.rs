@@ -0,0 +1,232 @@
+//! Variable-measure codes (UPC-A number system 2 and EAN-13 prefixes 20-29)
+//! which embed an item reference alongside the price or weight of a
+//! weighed item, such as deli counter or produce labels.
+
+use crate::{read_number, validate_digits, write_number, Standard, Upc, UpcError};
+
+/// Products for the GS1 "2-" weighting factor of each digit
+const WEIGHT_2_MINUS: [i8; 10] = [0, 2, 4, 6, 8, 9, 1, 3, 5, 7];
+
+/// Products for the GS1 "3" weighting factor of each digit
+const WEIGHT_3: [i8; 10] = [0, 3, 6, 9, 2, 5, 8, 1, 4, 7];
+
+/// Products for the GS1 "5+" weighting factor of each digit
+const WEIGHT_5_PLUS: [i8; 10] = [0, 5, 1, 6, 2, 7, 3, 8, 4, 9];
+
+/// Products for the GS1 "5-" weighting factor of each digit
+const WEIGHT_5_MINUS: [i8; 10] = [0, 5, 9, 4, 8, 3, 7, 2, 6, 1];
+
+/// Layout of the 10 digits following the prefix of a variable-measure code,
+/// which is chosen by each retailer or region
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MeasureLayout {
+    /// 5-digit item reference followed by a 5-digit value
+    Unchecked,
+
+    /// 5-digit item reference, price check digit and a 4-digit value
+    Checked4,
+
+    /// 4-digit item reference, price check digit and a 5-digit value
+    Checked5,
+}
+
+/// Which kind of code a [VariableMeasure] is held in
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MeasureFormat {
+    /// UPC-A with number system 2
+    UpcA,
+
+    /// EAN-13 with a prefix of 2 followed by the given digit (20-29)
+    Ean13(i8),
+}
+
+/// Item reference and price or weight embedded in a variable-measure code
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::{MeasureFormat, MeasureLayout, VariableMeasure};
+///
+/// let label = VariableMeasure {
+///     format: MeasureFormat::UpcA,
+///     layout: MeasureLayout::Checked4,
+///     item: 12345,
+///     value: 499,
+/// };
+/// let code = label.to_upc().unwrap();
+///
+/// assert_eq!("212345804995", code.to_string());
+/// assert_eq!(Ok(label), code.variable_measure(MeasureLayout::Checked4));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct VariableMeasure {
+    /// Kind of code this is held in
+    pub format: MeasureFormat,
+
+    /// Layout of the item reference and value
+    pub layout: MeasureLayout,
+
+    /// Item reference, identifying the product
+    pub item: u32,
+
+    /// Price or weight of the item, in units decided by the retailer such as
+    /// cents or grams
+    pub value: u32,
+}
+
+impl MeasureLayout {
+    /// Gets the number of digits of the item reference and of the value.
+    fn lengths(self) -> (usize, usize) {
+        match self {
+            MeasureLayout::Unchecked => (5, 5),
+            MeasureLayout::Checked4 => (5, 4),
+            MeasureLayout::Checked5 => (4, 5),
+        }
+    }
+}
+
+impl VariableMeasure {
+    /// Generates the code for this item, computing the price check digit (if
+    /// the layout has one) and the check digit.
+    ///
+    /// Fails with [UpcError::ValueTooLarge] if the item reference or value
+    /// doesn't fit in the layout's digits.
+    pub fn to_upc(&self) -> Result<Upc, UpcError> {
+        let (item_len, value_len) = self.layout.lengths();
+
+        let mut body = [0; 10];
+        write_number(&mut body[..item_len], self.item.into())?;
+        write_number(&mut body[10 - value_len..], self.value.into())?;
+
+        match self.layout {
+            MeasureLayout::Unchecked => (),
+            MeasureLayout::Checked4 => body[5] = price_check_digit(&body[6..]),
+            MeasureLayout::Checked5 => body[4] = price_check_digit(&body[5..]),
+        }
+
+        let upc = match self.format {
+            MeasureFormat::UpcA => {
+                let mut upc = [2; 11];
+                upc[1..].copy_from_slice(&body);
+
+                Standard::UpcA(upc)
+            }
+            MeasureFormat::Ean13(second) => {
+                let mut ean = [2, second, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+                ean[2..].copy_from_slice(&body);
+
+                Standard::Ean13(ean)
+            }
+        };
+
+        Upc::new(upc)
+    }
+}
+
+impl Upc {
+    /// Decodes the item reference and price or weight from a variable-measure
+    /// code with the given layout, validating its price check digit if the
+    /// layout has one.
+    ///
+    /// Fails with [UpcError::InvalidPrefix] for codes which aren't a UPC-A
+    /// with number system 2 or an EAN-13 with a prefix of 20-29, and with
+    /// [UpcError::PriceCheckMismatch] if the price check digit is wrong. The
+    /// code's own check digit isn't verified, so use [Upc::check] for that.
+    pub fn variable_measure(&self, layout: MeasureLayout) -> Result<VariableMeasure, UpcError> {
+        self.validate_upc_overflow()?;
+
+        let ean = match self.to_ean13() {
+            Some(Upc {
+                upc: Standard::Ean13(ean),
+                ..
+            }) => ean,
+            _ => return Err(UpcError::InvalidPrefix),
+        };
+
+        let format = match ean[..2] {
+            [0, 2] => MeasureFormat::UpcA,
+            [2, second] => MeasureFormat::Ean13(second),
+            _ => return Err(UpcError::InvalidPrefix),
+        };
+
+        let body = &ean[2..];
+        let (item_len, value_len) = layout.lengths();
+
+        let check = match layout {
+            MeasureLayout::Unchecked => None,
+            MeasureLayout::Checked4 => Some((body[5], price_check_digit(&body[6..]))),
+            MeasureLayout::Checked5 => Some((body[4], price_check_digit(&body[5..]))),
+        };
+
+        if let Some((found, expected)) = check {
+            if found != expected {
+                return Err(UpcError::PriceCheckMismatch { expected, found });
+            }
+        }
+
+        Ok(VariableMeasure {
+            format,
+            layout,
+            item: read_number(&body[..item_len]),
+            value: read_number(&body[10 - value_len..]),
+        })
+    }
+}
+
+/// Calculates the price check digit of a 4-digit price using the GS1
+/// weighting factors 2-, 2-, 3 and 5-, being the units digit of three times
+/// their sum.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::price_check_digit_4;
+///
+/// assert_eq!(Ok(9), price_check_digit_4([2, 8, 7, 5]));
+/// ```
+pub fn price_check_digit_4(price: [i8; 4]) -> Result<i8, UpcError> {
+    validate_digits(&price)?;
+
+    Ok(price_check_digit(&price))
+}
+
+/// Calculates the price check digit of a 5-digit price using the GS1
+/// weighting factors 5+, 2-, 5-, 5+ and 2-, being the digit whose 5- product
+/// brings their sum up to a multiple of 10.
+///
+/// # Examples
+///
+/// ```rust
+/// use upc_checker::price_check_digit_5;
+///
+/// assert_eq!(Ok(6), price_check_digit_5([1, 4, 6, 8, 5]));
+/// ```
+pub fn price_check_digit_5(price: [i8; 5]) -> Result<i8, UpcError> {
+    validate_digits(&price)?;
+
+    Ok(price_check_digit(&price))
+}
+
+/// Calculates the price check digit of an (already validated) 4 or 5-digit
+/// price.
+fn price_check_digit(price: &[i8]) -> i8 {
+    let product = |table: &[i8; 10], ind: usize| table[price[ind] as usize];
+
+    if price.len() == 4 {
+        let sum = product(&WEIGHT_2_MINUS, 0)
+            + product(&WEIGHT_2_MINUS, 1)
+            + product(&WEIGHT_3, 2)
+            + product(&WEIGHT_5_MINUS, 3);
+
+        sum * 3 % 10
+    } else {
+        let sum = product(&WEIGHT_5_PLUS, 0)
+            + product(&WEIGHT_2_MINUS, 1)
+            + product(&WEIGHT_5_MINUS, 2)
+            + product(&WEIGHT_5_PLUS, 3)
+            + product(&WEIGHT_2_MINUS, 4);
+        let target = (10 - sum % 10) % 10;
+
+        WEIGHT_5_MINUS.iter().position(|x| *x == target).unwrap() as i8
+    }
+}
@@ -0,0 +1,94 @@
+use upc_checker::{CodeAllocator, Standard, Upc, UpcError};
+
+/// Checks consecutive UPC-A codes are allocated for a UPC company prefix
+#[test]
+fn allocate_upc_a() {
+    let my_allocator = CodeAllocator::new(&[0, 6, 1, 4, 1, 4, 1], 3).unwrap();
+    let my_codes: Vec<String> = my_allocator.take(3).map(|x| x.to_string()).collect();
+
+    assert_eq!(
+        vec!["614141000036", "614141000043", "614141000050"],
+        my_codes
+    );
+}
+
+/// Checks EAN-13 codes are allocated for other company prefixes
+#[test]
+fn allocate_ean13() {
+    let mut my_allocator = CodeAllocator::new(&[5, 0, 1, 2, 3, 4, 5], 9).unwrap();
+    let my_upc = my_allocator.next_upc().unwrap();
+
+    assert_eq!(
+        Standard::Ean13([5, 0, 1, 2, 3, 4, 5, 0, 0, 0, 0, 9]),
+        my_upc.upc
+    );
+    assert_eq!("5012345000091", my_upc.to_string());
+}
+
+/// Checks every allocated code passes its check
+#[test]
+fn allocate_valid_codes() {
+    let my_allocator = CodeAllocator::new(&[5, 0, 1, 2, 3, 4, 5], 0).unwrap();
+
+    for my_upc in my_allocator.take(200) {
+        assert_eq!(Ok(true), my_upc.check(), "{}", my_upc);
+    }
+}
+
+/// Checks allocation stops once the item references run out
+#[test]
+fn allocate_capacity_exhausted() {
+    let mut my_allocator = CodeAllocator::new(&[5, 0, 1, 2, 3, 4, 5], 99_998).unwrap();
+
+    assert_eq!(2, my_allocator.remaining());
+    assert_eq!(
+        "5012345999982",
+        my_allocator.next_upc().unwrap().to_string()
+    );
+    assert_eq!(
+        "5012345999999",
+        my_allocator.next_upc().unwrap().to_string()
+    );
+    assert_eq!(0, my_allocator.remaining());
+    assert_eq!(Err(UpcError::CapacityExhausted), my_allocator.next_upc());
+    assert_eq!(None, my_allocator.next());
+}
+
+/// Checks a full-length company prefix allocates exactly one code
+#[test]
+fn allocate_single_code() {
+    let my_allocator = CodeAllocator::new(&[5, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0], 0).unwrap();
+    let my_codes: Vec<Upc> = my_allocator.collect();
+
+    assert_eq!(1, my_codes.len());
+    assert_eq!("5012345678900", my_codes[0].to_string());
+}
+
+/// Checks the iterator's size hint matches the remaining capacity
+#[test]
+fn allocate_size_hint() {
+    let my_allocator = CodeAllocator::new(&[5, 0, 1, 2, 3, 4, 5, 6, 7, 8], 90).unwrap();
+
+    assert_eq!((10, Some(10)), my_allocator.size_hint());
+    assert_eq!(10, my_allocator.count());
+}
+
+/// Checks invalid company prefixes and starting item references are rejected
+#[test]
+fn allocate_invalid() {
+    assert_eq!(
+        Err(UpcError::InvalidPrefixLength(3)),
+        CodeAllocator::new(&[5, 0, 1], 0).map(|_| ())
+    );
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 2,
+            found: 10
+        }),
+        CodeAllocator::new(&[5, 0, 10, 2, 3, 4, 5], 0).map(|_| ())
+    );
+    assert_eq!(
+        Err(UpcError::ValueTooLarge(100_000)),
+        CodeAllocator::new(&[5, 0, 1, 2, 3, 4, 5], 100_000).map(|_| ())
+    );
+}
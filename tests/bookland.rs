@@ -0,0 +1,58 @@
+use upc_checker::{Barcode, BooklandPrice, Currency, UpcError};
+
+/// Checks that prices are decoded in each currency from the add-on's first
+/// digit
+#[test]
+fn bookland_currencies() {
+    let cases = [
+        ("00799", Currency::Gbp, 799),
+        ("12500", Currency::Gbp, 12500),
+        ("32495", Currency::Aud, 2495),
+        ("41999", Currency::Nzd, 1999),
+        ("51299", Currency::Usd, 1299),
+        ("61599", Currency::Cad, 1599),
+    ];
+
+    for (supplement, currency, amount) in cases.iter() {
+        let my_barcode: Barcode = format!("978-3-16-148410-0 {}", supplement).parse().unwrap();
+
+        assert_eq!(
+            Ok(Some(BooklandPrice::Price {
+                currency: *currency,
+                amount: *amount
+            })),
+            my_barcode.bookland_price()
+        );
+    }
+}
+
+/// Checks the special `9xxxx` codes
+#[test]
+fn bookland_special_codes() {
+    let cases = [
+        ("90000", BooklandPrice::NoSuggestedPrice),
+        ("99990", BooklandPrice::Used),
+        ("99991", BooklandPrice::Complimentary),
+        ("95000", BooklandPrice::Internal(95000)),
+        ("71234", BooklandPrice::Unassigned(71234)),
+    ];
+
+    for (supplement, price) in cases.iter() {
+        let my_barcode: Barcode = format!("9780306406157 {}", supplement).parse().unwrap();
+
+        assert_eq!(Ok(Some(*price)), my_barcode.bookland_price());
+    }
+}
+
+/// Checks that only Bookland codes with an EAN-5 add-on carry a price
+#[test]
+fn bookland_not_priced() {
+    let my_barcode: Barcode = "9780306406157 12".parse().unwrap();
+    assert_eq!(Ok(None), my_barcode.bookland_price());
+
+    let my_barcode: Barcode = "9780306406157".parse().unwrap();
+    assert_eq!(Ok(None), my_barcode.bookland_price());
+
+    let my_barcode: Barcode = "4006381333931 51299".parse().unwrap();
+    assert_eq!(Err(UpcError::InvalidPrefix), my_barcode.bookland_price());
+}
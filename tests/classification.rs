@@ -0,0 +1,131 @@
+use upc_checker::{Classification, Standard, Upc, UpcError};
+
+/// Checks regular UPC-A and EAN-13 codes are classified as regular items
+#[test]
+fn classify_regular() {
+    let my_upc_a: Upc = "036000291452".parse().unwrap();
+    let my_ean13: Upc = "4006381333931".parse().unwrap();
+
+    assert_eq!(Ok(Classification::Regular), my_upc_a.classify());
+    assert_eq!(Ok(Classification::Regular), my_ean13.classify());
+}
+
+/// Checks each UPC-A number system is classified
+#[test]
+fn classify_upc_a_number_systems() {
+    let my_cases = [
+        ("212345000007", Classification::VariableMeasure),
+        ("345678901236", Classification::Drug),
+        ("412345678903", Classification::RestrictedInStore),
+        ("512345678016", Classification::Coupon),
+        ("614141000036", Classification::Regular),
+        ("725272730706", Classification::Regular),
+    ];
+
+    for (my_code, my_classification) in my_cases.iter() {
+        let my_upc: Upc = my_code.parse().unwrap();
+
+        assert_eq!(Ok(*my_classification), my_upc.classify(), "{}", my_code);
+    }
+}
+
+/// Checks special EAN-13 prefixes are classified
+#[test]
+fn classify_ean13_prefixes() {
+    let my_cases = [
+        ("2123456789012", Classification::VariableMeasure),
+        ("9783161484100", Classification::Bookland),
+        ("9770317847100", Classification::Serial),
+        ("9801234567892", Classification::RefundReceipt),
+        ("9991234567890", Classification::Coupon),
+        ("1401234567897", Classification::Unassigned),
+    ];
+
+    for (my_code, my_classification) in my_cases.iter() {
+        let my_upc: Upc = my_code.parse().unwrap();
+
+        assert_eq!(Ok(*my_classification), my_upc.classify(), "{}", my_code);
+    }
+}
+
+/// Checks EAN-8 codes starting with 0 or 2 are restricted to in-store use
+#[test]
+fn classify_ean8_restricted() {
+    let my_zero: Upc = "01234565".parse().unwrap();
+    let my_two: Upc = "21234569".parse().unwrap();
+    let my_regular: Upc = "50123452".parse().unwrap();
+
+    assert_eq!(Ok(Classification::RestrictedInStore), my_zero.classify());
+    assert_eq!(Ok(Classification::RestrictedInStore), my_two.classify());
+    assert_eq!(Ok(Classification::Regular), my_regular.classify());
+    assert_eq!(Ok(Some("United Kingdom")), my_regular.member_organization());
+}
+
+/// Checks UPC-E and GTIN-14 codes are classified by their GTIN-13 form
+#[test]
+fn classify_upc_e_and_gtin14() {
+    let my_upc_e = Upc::parse_upc_e("04252614").unwrap();
+    let my_gtin14: Upc = "10614141000033".parse().unwrap();
+
+    assert_eq!(Ok(Classification::Regular), my_upc_e.classify());
+    assert_eq!(Ok(Classification::Regular), my_gtin14.classify());
+    assert_eq!(
+        Ok(Some("United States and Canada")),
+        my_gtin14.member_organization()
+    );
+}
+
+/// Checks the member organization is found from the GS1 prefix
+#[test]
+fn member_organization_countries() {
+    let my_cases = [
+        ("036000291452", Some("United States and Canada")),
+        ("4006381333931", Some("Germany")),
+        ("4501234567896", Some("Japan")),
+        ("4901234567894", Some("Japan")),
+        ("7541234567868", Some("Canada")),
+        ("6171234567899", Some("Cameroon")),
+        ("6301234567890", Some("Qatar")),
+        ("6801234567895", Some("China")),
+    ];
+
+    for (my_code, my_country) in my_cases.iter() {
+        let my_upc: Upc = my_code.parse().unwrap();
+
+        assert_eq!(Ok(*my_country), my_upc.member_organization(), "{}", my_code);
+    }
+}
+
+/// Checks restricted and special prefixes have no member organization
+#[test]
+fn member_organization_special() {
+    let my_cases = [
+        "212345000007",
+        "412345678903",
+        "9783161484100",
+        "9770317847100",
+        "1401234567897",
+    ];
+
+    for my_code in my_cases.iter() {
+        let my_upc: Upc = my_code.parse().unwrap();
+
+        assert_eq!(Ok(None), my_upc.member_organization(), "{}", my_code);
+    }
+}
+
+/// Checks codes with overflowing digits are rejected rather than classified
+#[test]
+fn classify_overflow() {
+    let my_upc = Upc {
+        upc: Standard::UpcA([-1, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]),
+        check_digit: 2,
+    };
+    let my_error = UpcError::UpcOverflow {
+        position: 0,
+        found: -1,
+    };
+
+    assert_eq!(Err(my_error.clone()), my_upc.classify());
+    assert_eq!(Err(my_error), my_upc.member_organization());
+}
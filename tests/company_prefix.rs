@@ -0,0 +1,149 @@
+use upc_checker::{Standard, Upc, UpcError};
+
+/// Checks a UPC-A is split by a given company prefix length
+#[test]
+fn gtin_parts_upc_a() {
+    let my_upc: Upc = "614141000036".parse().unwrap();
+    let my_parts = my_upc.gtin_parts(7).unwrap();
+
+    assert_eq!(0, my_parts.indicator());
+    assert_eq!(&[0, 6, 1, 4, 1, 4, 1], my_parts.company_prefix());
+    assert_eq!(&[0, 0, 0, 0, 3], my_parts.item_reference());
+    assert_eq!(6, my_parts.check_digit());
+}
+
+/// Checks the packaging indicator of a GTIN-14 is kept apart
+#[test]
+fn gtin_parts_gtin14() {
+    let my_upc: Upc = "10614141000033".parse().unwrap();
+    let my_parts = my_upc.gtin_parts(7).unwrap();
+
+    assert_eq!(1, my_parts.indicator());
+    assert_eq!(&[0, 6, 1, 4, 1, 4, 1], my_parts.company_prefix());
+    assert_eq!("1-0614141-00003-3", my_parts.to_string());
+}
+
+/// Checks UPC-E codes are split after expanding to UPC-A
+#[test]
+fn gtin_parts_upc_e() {
+    let my_upc = Upc::parse_upc_e("04252614").unwrap();
+    let my_parts = my_upc.gtin_parts(7).unwrap();
+
+    assert_eq!(&[0, 0, 4, 2, 1, 0, 0], my_parts.company_prefix());
+    assert_eq!(&[0, 0, 5, 2, 6], my_parts.item_reference());
+}
+
+/// Checks every company prefix length from 4 to 12 is allowed
+#[test]
+fn gtin_parts_lengths() {
+    let my_upc: Upc = "5012345678900".parse().unwrap();
+
+    for my_len in 4..=12 {
+        let my_parts = my_upc.gtin_parts(my_len).unwrap();
+
+        assert_eq!(my_len, my_parts.company_prefix().len());
+        assert_eq!(12 - my_len, my_parts.item_reference().len());
+    }
+
+    assert_eq!(Err(UpcError::InvalidPrefixLength(3)), my_upc.gtin_parts(3));
+    assert_eq!(
+        Err(UpcError::InvalidPrefixLength(13)),
+        my_upc.gtin_parts(13)
+    );
+}
+
+/// Checks EAN-8 codes aren't split as they have no company prefix
+#[test]
+fn gtin_parts_ean8() {
+    let my_upc: Upc = "50123452".parse().unwrap();
+
+    assert_eq!(Err(UpcError::InvalidPrefix), my_upc.gtin_parts(7));
+}
+
+/// Checks codes with overflowing digits aren't split
+#[test]
+fn gtin_parts_overflow() {
+    let my_upc = Upc {
+        upc: Standard::UpcA([6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 13]),
+        check_digit: 6,
+    };
+
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 10,
+            found: 13
+        }),
+        my_upc.gtin_parts(7)
+    );
+}
+
+/// Checks codes with overflowing digits are rejected before the lookup
+#[test]
+fn lookup_gtin_parts_overflow() {
+    let my_upc = Upc {
+        upc: Standard::UpcA([-1, 1, 4, 1, 4, 1, 0, 0, 0, 0, 3]),
+        check_digit: 6,
+    };
+
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 0,
+            found: -1
+        }),
+        my_upc.lookup_gtin_parts()
+    );
+}
+
+/// Checks codes from companies missing from the table aren't split with a
+/// guessed company prefix length
+#[test]
+fn lookup_gtin_parts_unknown() {
+    let my_cases = [
+        "614141000036",
+        "4006381333931",
+        "5012345678900",
+        "6901234567892",
+        "00000000000000",
+    ];
+
+    for my_code in my_cases.iter() {
+        let my_upc: Upc = my_code.parse().unwrap();
+
+        assert_eq!(
+            Err(UpcError::UnknownCompanyPrefix),
+            my_upc.lookup_gtin_parts(),
+            "{}",
+            my_code
+        );
+    }
+}
+
+/// Checks restricted circulation numbers aren't split, as they don't carry
+/// a company prefix
+#[test]
+fn lookup_gtin_parts_restricted() {
+    let my_cases = ["2012345678903", "212345678909"];
+
+    for my_code in my_cases.iter() {
+        let my_upc: Upc = my_code.parse().unwrap();
+
+        assert_eq!(
+            Err(UpcError::UnknownCompanyPrefix),
+            my_upc.lookup_gtin_parts(),
+            "{}",
+            my_code
+        );
+    }
+}
+
+/// Checks codes are matched against a licensed company prefix
+#[test]
+fn has_company_prefix() {
+    let my_upc: Upc = "061414112345".parse().unwrap();
+    let my_gtin14: Upc = "10614141000033".parse().unwrap();
+
+    assert!(!my_upc.has_company_prefix(&[0, 6, 1, 4, 1, 4, 1]));
+    assert!(my_upc.has_company_prefix(&[0, 0, 6, 1, 4, 1, 4, 1]));
+    assert!(my_gtin14.has_company_prefix(&[0, 6, 1, 4, 1, 4, 1]));
+    assert!(!my_gtin14.has_company_prefix(&[0, 6, 1]));
+}
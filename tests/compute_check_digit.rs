@@ -0,0 +1,35 @@
+use upc_checker::{Standard, Upc, UpcError};
+
+/// Checks that the computed check digit matches a known-good
+/// [UPC-A](https://en.wikipedia.org/wiki/Universal_Product_Code#Encoding)
+#[test]
+fn compute_check_digit_upc_a() {
+    let my_upc = Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 4, 1, 4, 5]);
+
+    assert_eq!(Ok(7), my_upc.compute_check_digit());
+}
+
+/// Checks that [Upc::new] mints a complete code which then passes
+/// [Upc::check]
+#[test]
+fn new_upc_a() {
+    let my_upc_struct = Upc::new(Standard::UpcA([6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 3])).unwrap();
+
+    assert_eq!(6, my_upc_struct.check_digit);
+    assert_eq!(Ok(true), my_upc_struct.check());
+}
+
+/// Checks that overflowing payloads are refused rather than given a check
+/// digit
+#[test]
+fn new_upc_a_overflow() {
+    let my_upc = Standard::UpcA([9, 9, 9, 9, 9, 12, 9, 9, 9, 9, 9]);
+
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 5,
+            found: 12
+        }),
+        Upc::new(my_upc)
+    );
+}
@@ -0,0 +1,53 @@
+use upc_checker::{Standard, Upc};
+
+/// Real-world UPC-A codes (GS1 example codes and retail products) with their
+/// printed check digits, used to confirm the position-based weighting
+const VALID_UPC_A: [[i8; 12]; 13] = [
+    [0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5, 2],
+    [0, 3, 6, 0, 0, 0, 2, 4, 1, 4, 5, 7],
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 5],
+    [6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 3, 6],
+    [0, 4, 9, 0, 0, 0, 0, 2, 8, 9, 1, 1],
+    [0, 2, 8, 4, 0, 0, 0, 9, 0, 8, 5, 8],
+    [0, 4, 2, 1, 0, 0, 0, 0, 5, 2, 6, 4],
+    [0, 7, 0, 6, 6, 2, 4, 0, 4, 0, 7, 2],
+    [0, 4, 1, 2, 2, 0, 5, 7, 6, 4, 6, 3],
+    [0, 1, 6, 0, 0, 0, 2, 7, 5, 2, 7, 0],
+    [0, 3, 8, 0, 0, 0, 1, 3, 8, 4, 1, 6],
+    [0, 4, 4, 0, 0, 0, 0, 3, 2, 0, 2, 9],
+    [7, 2, 5, 2, 7, 2, 7, 3, 0, 7, 0, 6],
+];
+
+/// Builds a [Upc] from a full 12-digit UPC-A including its check digit
+fn upc_a(digits: [i8; 12]) -> Upc {
+    let mut payload = [0; 11];
+    payload.copy_from_slice(&digits[..11]);
+
+    Upc {
+        upc: Standard::UpcA(payload),
+        check_digit: digits[11],
+    }
+}
+
+/// Checks every code in the conformance corpus is accepted for
+/// [UPC-A](https://en.wikipedia.org/wiki/Universal_Product_Code#Encoding)
+#[test]
+fn conformance_upc_a_valid() {
+    for digits in VALID_UPC_A.iter() {
+        assert_eq!(Ok(true), upc_a(*digits).check(), "{:?}", digits);
+    }
+}
+
+/// Checks that every other check digit is rejected for each code in the
+/// conformance corpus, so only the printed check digit verifies
+#[test]
+fn conformance_upc_a_wrong_check_digit() {
+    for digits in VALID_UPC_A.iter() {
+        for check_digit in (0..=9).filter(|x| *x != digits[11]) {
+            let mut upc = upc_a(*digits);
+            upc.check_digit = check_digit;
+
+            assert_eq!(Ok(false), upc.check(), "{:?}", upc);
+        }
+    }
+}
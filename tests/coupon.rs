@@ -0,0 +1,40 @@
+use upc_checker::{Coupon, CouponFormat, Upc, UpcError};
+
+/// Checks that number system 5 UPC-A coupons are split into their fields
+#[test]
+fn coupon_upc_a() {
+    let my_upc_struct: Upc = "512345678016".parse().unwrap();
+    let my_coupon = my_upc_struct.coupon().unwrap();
+
+    assert_eq!(CouponFormat::UpcA, my_coupon.format);
+    assert_eq!([1, 2, 3, 4, 5], my_coupon.manufacturer);
+    assert_eq!([6, 7, 8], my_coupon.family);
+    assert_eq!([0, 1], my_coupon.value_code);
+    assert_eq!(Ok(my_upc_struct), my_coupon.to_upc());
+}
+
+/// Checks that 99-prefixed EAN-13 coupons are split into their fields
+#[test]
+fn coupon_ean13() {
+    let my_coupon = Coupon {
+        format: CouponFormat::Ean13,
+        manufacturer: [0, 4, 1, 2, 2],
+        family: [1, 0, 0],
+        value_code: [5, 5],
+    };
+    let my_upc_struct = my_coupon.to_upc().unwrap();
+
+    assert_eq!("9904122100550", my_upc_struct.to_string());
+    assert_eq!(Ok(true), my_upc_struct.check());
+    assert_eq!(Ok(my_coupon), my_upc_struct.coupon());
+}
+
+/// Checks that non-coupon codes are refused
+#[test]
+fn coupon_invalid_prefix() {
+    for input in ["036000241457", "4006381333931", "96385074"].iter() {
+        let my_upc_struct: Upc = input.parse().unwrap();
+
+        assert_eq!(Err(UpcError::InvalidPrefix), my_upc_struct.coupon());
+    }
+}
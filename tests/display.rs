@@ -0,0 +1,34 @@
+use upc_checker::{Standard, Upc};
+
+/// Checks that every standard renders its canonical digit string, both plain
+/// and grouped as printed under the barcode
+#[test]
+fn display_upc() {
+    let cases = [
+        ("036000241457", "0 36000 24145 7"),
+        ("4006381333931", "4 006381 333931"),
+        ("96385074", "9638 5074"),
+        ("10614141000033", "1 06 14141 00003 3"),
+    ];
+
+    for (plain, grouped) in cases.iter() {
+        let my_upc_struct: Upc = plain.parse().unwrap();
+
+        assert_eq!(*plain, format!("{}", my_upc_struct));
+        assert_eq!(*grouped, format!("{:#}", my_upc_struct));
+    }
+
+    let my_upc_e = Upc::parse_upc_e("04252614").unwrap();
+
+    assert_eq!("04252614", format!("{}", my_upc_e));
+    assert_eq!("0 425261 4", format!("{:#}", my_upc_e));
+}
+
+/// Checks that a [Standard] renders only its payload digits
+#[test]
+fn display_standard() {
+    let my_upc = Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 4, 1, 4, 5]);
+
+    assert_eq!("03600024145", format!("{}", my_upc));
+    assert_eq!("0 36000 24145", format!("{:#}", my_upc));
+}
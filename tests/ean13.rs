@@ -0,0 +1,45 @@
+use upc_checker::{Standard, Upc};
+
+/// Checks if check_upc is returning the right values for
+/// [EAN-13](https://en.wikipedia.org/wiki/International_Article_Number)
+#[test]
+fn valid_ean13() {
+    let my_upc = Standard::Ean13([4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3]);
+    let my_check_code: i8 = 1;
+
+    let my_upc_struct = Upc {
+        upc: my_upc,
+        check_digit: my_check_code,
+    };
+
+    assert_eq!(Ok(true), my_upc_struct.check());
+}
+
+/// Checks that a UPC-A converts to its `0`-prefixed EAN-13 and back again
+#[test]
+fn upc_a_ean13_round_trip() {
+    let my_upc_struct = Upc {
+        upc: Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]),
+        check_digit: 2,
+    };
+
+    let my_ean = my_upc_struct.to_ean13().unwrap();
+
+    assert_eq!(
+        Standard::Ean13([0, 0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]),
+        my_ean.upc
+    );
+    assert_eq!(Ok(true), my_ean.check());
+    assert_eq!(Some(my_upc_struct), my_ean.to_upc_a());
+}
+
+/// Checks that EAN-13 codes without a leading zero have no UPC-A form
+#[test]
+fn ean13_to_upc_a_non_zero() {
+    let my_upc_struct = Upc {
+        upc: Standard::Ean13([4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3]),
+        check_digit: 1,
+    };
+
+    assert_eq!(None, my_upc_struct.to_upc_a());
+}
@@ -0,0 +1,39 @@
+use upc_checker::{Standard, Upc};
+
+/// Checks if check_upc is returning the right values for
+/// [EAN-8](https://en.wikipedia.org/wiki/EAN-8)
+#[test]
+fn valid_ean8() {
+    let my_upc = Standard::Ean8([9, 6, 3, 8, 5, 0, 7]);
+    let my_check_code: i8 = 4;
+
+    let my_upc_struct = Upc {
+        upc: my_upc,
+        check_digit: my_check_code,
+    };
+
+    assert_eq!(Ok(true), my_upc_struct.check());
+}
+
+/// Checks that EAN-8 check digits are computed with odd positions weighted
+/// ×3, unlike the even-position weighting of EAN-13
+#[test]
+fn compute_check_digit_ean8() {
+    assert_eq!(
+        Ok(7),
+        Standard::Ean8([7, 3, 5, 1, 3, 5, 3]).compute_check_digit()
+    );
+    assert_eq!(
+        Ok(5),
+        Standard::Ean8([4, 0, 1, 7, 0, 7, 2]).compute_check_digit()
+    );
+}
+
+/// Checks that EAN-8 codes have no UPC-A or EAN-13 equivalent
+#[test]
+fn ean8_no_conversion() {
+    let my_upc_struct = Upc::new(Standard::Ean8([9, 6, 3, 8, 5, 0, 7])).unwrap();
+
+    assert_eq!(None, my_upc_struct.to_ean13());
+    assert_eq!(None, my_upc_struct.to_upc_a());
+}
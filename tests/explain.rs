@@ -0,0 +1,58 @@
+use upc_checker::{Contribution, Upc, UpcError};
+
+/// Checks that a mistyped check digit is explained with the expected one and
+/// the weighted sum behind it
+#[test]
+fn explain_invalid_upc_a() {
+    let my_upc_struct: Upc = "036000241452".parse().unwrap();
+    let my_report = my_upc_struct.explain().unwrap();
+
+    assert!(!my_report.is_valid());
+    assert_eq!(7, my_report.expected);
+    assert_eq!(2, my_report.found);
+    assert_eq!(53, my_report.weighted_sum);
+    assert_eq!(11, my_report.contributions().len());
+    assert_eq!(
+        Contribution {
+            position: 2,
+            digit: 6,
+            weight: 3,
+            value: 18,
+        },
+        my_report.contributions()[2]
+    );
+    assert_eq!(
+        my_report.weighted_sum,
+        my_report
+            .contributions()
+            .iter()
+            .map(|x| x.value)
+            .sum::<u16>()
+    );
+    assert_eq!("expected 7, got 2", my_report.to_string());
+    assert_eq!(
+        "expected 7, got 2 (weighted sum 53 = 0×3 + 3×1 + 6×3 + 0×1 + 0×3 + 0×1 + 2×3 + 4×1 + 1×3 + 4×1 + 5×3)",
+        format!("{:#}", my_report)
+    );
+}
+
+/// Checks that UPC-E codes are explained through their expanded UPC-A form
+#[test]
+fn explain_upc_e() {
+    let my_report = Upc::parse_upc_e("04252614").unwrap().explain().unwrap();
+
+    assert!(my_report.is_valid());
+    assert_eq!(11, my_report.contributions().len());
+}
+
+/// Checks that overflowing codes can't be explained
+#[test]
+fn explain_overflow() {
+    let mut my_upc_struct: Upc = "036000241457".parse().unwrap();
+    my_upc_struct.check_digit = 10;
+
+    assert_eq!(
+        Err(UpcError::CheckDigitOverflow { found: 10 }),
+        my_upc_struct.explain()
+    );
+}
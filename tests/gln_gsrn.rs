@@ -0,0 +1,89 @@
+use upc_checker::{Gln, Gsrn, UpcError};
+
+/// Checks a GLN is created with its check digit and passes
+#[test]
+fn gln_new() {
+    let my_gln = Gln::new([0, 6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 0]).unwrap();
+
+    assert_eq!(5, my_gln.check_digit);
+    assert_eq!(Ok(true), my_gln.check());
+}
+
+/// Checks a GLN with the wrong check digit or overflowing digits fails
+#[test]
+fn gln_invalid() {
+    let mut my_gln: Gln = "0614141000005".parse().unwrap();
+    my_gln.check_digit = 4;
+
+    assert_eq!(Ok(false), my_gln.check());
+
+    my_gln.gln[3] = 11;
+
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 3,
+            found: 11
+        }),
+        my_gln.check()
+    );
+}
+
+/// Checks GLNs are parsed with or without the application identifier
+#[test]
+fn gln_parse() {
+    let my_expected = Gln::new([0, 6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 0]).unwrap();
+
+    assert_eq!(Ok(my_expected.clone()), "0614141000005".parse());
+    assert_eq!(Ok(my_expected), "(414) 0614141 00000 5".parse());
+    assert_eq!(
+        Err(UpcError::InvalidLength(12)),
+        "(414)061414100000".parse::<Gln>()
+    );
+}
+
+/// Checks GLNs are formatted with and without the application identifier
+#[test]
+fn gln_display() {
+    let my_gln: Gln = "0614141000005".parse().unwrap();
+
+    assert_eq!("0614141000005", my_gln.to_string());
+    assert_eq!("(414)0614141000005", format!("{:#}", my_gln));
+}
+
+/// Checks a GSRN is created with its check digit and passes
+#[test]
+fn gsrn_new() {
+    let my_gsrn = Gsrn::new([0, 6, 1, 4, 1, 4, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0]).unwrap();
+
+    assert_eq!(2, my_gsrn.check_digit);
+    assert_eq!(Ok(true), my_gsrn.check());
+}
+
+/// Checks a GSRN with the wrong check digit fails
+#[test]
+fn gsrn_invalid() {
+    let mut my_gsrn: Gsrn = "061414112345678902".parse().unwrap();
+    my_gsrn.check_digit = 3;
+
+    assert_eq!(Ok(false), my_gsrn.check());
+
+    my_gsrn.check_digit = 12;
+
+    assert_eq!(
+        Err(UpcError::CheckDigitOverflow { found: 12 }),
+        my_gsrn.check()
+    );
+}
+
+/// Checks GSRNs are parsed and formatted with the application identifier
+#[test]
+fn gsrn_parse_display() {
+    let my_gsrn: Gsrn = "(8018)061414112345678902".parse().unwrap();
+
+    assert_eq!("061414112345678902", my_gsrn.to_string());
+    assert_eq!("(8018)061414112345678902", format!("{:#}", my_gsrn));
+    assert_eq!(
+        Err(UpcError::InvalidLength(13)),
+        "0614141000005".parse::<Gsrn>()
+    );
+}
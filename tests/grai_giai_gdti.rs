@@ -0,0 +1,151 @@
+use upc_checker::{Gdti, Giai, Grai, Serial, UpcError};
+
+/// Checks a GRAI is created with its check digit and serial component
+#[test]
+fn grai_new() {
+    let my_serial: Serial = "ABC".parse().unwrap();
+    let my_grai = Grai::new([0, 6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 1], Some(my_serial)).unwrap();
+
+    assert_eq!(2, my_grai.check_digit);
+    assert_eq!(Ok(true), my_grai.check());
+    assert_eq!("00614141000012ABC", my_grai.to_string());
+}
+
+/// Checks GRAIs are parsed with and without a serial component
+#[test]
+fn grai_parse() {
+    let my_grai: Grai = "(8003)00614141000012".parse().unwrap();
+    let my_serialized: Grai = "00614141000012 PALLET-7".parse().unwrap();
+
+    assert_eq!(None, my_grai.serial);
+    assert_eq!(Ok(true), my_grai.check());
+    assert_eq!("(8003)00614141000012", format!("{:#}", my_grai));
+    assert_eq!(
+        Some("PALLET-7"),
+        my_serialized.serial.as_ref().map(|x| x.as_str())
+    );
+}
+
+/// Checks GRAIs without the filler digit, or with bad serials, are rejected
+#[test]
+fn grai_parse_invalid() {
+    assert_eq!(
+        Err(UpcError::InvalidPrefix),
+        "10614141000012".parse::<Grai>()
+    );
+    assert_eq!(
+        Err(UpcError::InvalidLength(13)),
+        "0061414100001".parse::<Grai>()
+    );
+    assert_eq!(
+        Err(UpcError::InvalidCharacter {
+            position: 3,
+            found: '#'
+        }),
+        "00614141000012ABC#".parse::<Grai>()
+    );
+    assert_eq!(
+        Err(UpcError::InvalidSerialLength { max: 16, found: 17 }),
+        "00614141000012ABCDEFGHIJKLMNOPQ".parse::<Grai>()
+    );
+}
+
+/// Checks a GRAI's serial component is limited when checked
+#[test]
+fn grai_check_serial_length() {
+    let my_serial: Serial = "ABCDEFGHIJKLMNOPQ".parse().unwrap();
+    let mut my_grai: Grai = "00614141000012".parse().unwrap();
+
+    assert_eq!(
+        Err(UpcError::InvalidSerialLength { max: 16, found: 17 }),
+        Grai::new(my_grai.grai, Some(my_serial))
+    );
+
+    my_grai.serial = Some(my_serial);
+
+    assert_eq!(
+        Err(UpcError::InvalidSerialLength { max: 16, found: 17 }),
+        my_grai.check()
+    );
+}
+
+/// Checks a GDTI is created, parsed and formatted with its serial component
+#[test]
+fn gdti_new_parse() {
+    let my_serial: Serial = "INV-42".parse().unwrap();
+    let my_gdti = Gdti::new([0, 6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 2], Some(my_serial)).unwrap();
+
+    assert_eq!(9, my_gdti.check_digit);
+    assert_eq!(Ok(my_gdti.clone()), "(253) 0614141000029 INV-42".parse());
+    assert_eq!("(253)0614141000029INV-42", format!("{:#}", my_gdti));
+}
+
+/// Checks a GDTI with the wrong check digit fails
+#[test]
+fn gdti_invalid() {
+    let mut my_gdti: Gdti = "0614141000029".parse().unwrap();
+
+    assert_eq!(Ok(true), my_gdti.check());
+
+    my_gdti.check_digit = 0;
+
+    assert_eq!(Ok(false), my_gdti.check());
+    assert_eq!(
+        Err(UpcError::InvalidSerialLength { max: 17, found: 18 }),
+        "0614141000029ABCDEFGHIJKLMNOPQR".parse::<Gdti>()
+    );
+}
+
+/// Checks GIAIs are parsed and matched against their company prefix
+#[test]
+fn giai_parse() {
+    let my_giai: Giai = "(8004)0614141ABC-123".parse().unwrap();
+
+    assert_eq!("0614141ABC-123", my_giai.as_str());
+    assert_eq!("0614141ABC-123", my_giai.to_string());
+    assert!(my_giai.has_company_prefix(&[0, 6, 1, 4, 1, 4, 1]));
+    assert!(!my_giai.has_company_prefix(&[0, 6, 1, 4, 1, 4, 2]));
+}
+
+/// Checks invalid GIAIs are rejected
+#[test]
+fn giai_parse_invalid() {
+    assert_eq!(
+        Err(UpcError::InvalidCharacter {
+            position: 2,
+            found: 'A'
+        }),
+        "06ABC".parse::<Giai>()
+    );
+    assert_eq!(Err(UpcError::InvalidLength(3)), "061".parse::<Giai>());
+    assert_eq!(
+        Err(UpcError::InvalidSerialLength { max: 30, found: 31 }),
+        "0614141ABCDEFGHIJKLMNOPQRSTUVWX".parse::<Giai>()
+    );
+    assert_eq!(
+        Err(UpcError::InvalidSerialLength { max: 30, found: 0 }),
+        "(8004)".parse::<Giai>()
+    );
+}
+
+/// Checks serials are limited to GS1's character set 82
+#[test]
+fn serial_characters() {
+    let my_serial: Serial = "!\"%&'()*+,-./09:;<=>?AZ_az".parse().unwrap();
+
+    assert_eq!("!\"%&'()*+,-./09:;<=>?AZ_az", my_serial.as_str());
+    assert_eq!(
+        Err(UpcError::InvalidCharacter {
+            position: 1,
+            found: ' '
+        }),
+        "A B".parse::<Serial>()
+    );
+    assert_eq!(
+        Err(UpcError::InvalidCharacter {
+            position: 0,
+            found: 'é'
+        }),
+        "é".parse::<Serial>()
+    );
+}
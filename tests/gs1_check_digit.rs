@@ -0,0 +1,62 @@
+use upc_checker::{gs1_check, gs1_check_digit, Standard, UpcError};
+
+/// Checks the engine gives the same check digit as each [Standard]
+#[test]
+fn gs1_check_digit_matches_standards() {
+    let my_codes = [
+        Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]),
+        Standard::Ean13([4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3]),
+        Standard::Ean8([5, 0, 1, 2, 3, 4, 5]),
+        Standard::Gtin14([1, 0, 6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 3]),
+    ];
+
+    for my_code in my_codes.iter() {
+        let my_digits: Vec<i8> = my_code
+            .to_string()
+            .bytes()
+            .map(|x| (x - b'0') as i8)
+            .collect();
+
+        assert_eq!(
+            my_code.compute_check_digit(),
+            gs1_check_digit(&my_digits),
+            "{:?}",
+            my_code
+        );
+    }
+}
+
+/// Checks the engine works for keys of any length, such as an SSCC
+#[test]
+fn gs1_check_digit_any_length() {
+    let my_sscc = [1, 0, 6, 1, 4, 1, 4, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+    assert_eq!(Ok(7), gs1_check_digit(&my_sscc));
+    assert_eq!(Ok(true), gs1_check(&my_sscc, 7));
+    assert_eq!(Ok(false), gs1_check(&my_sscc, 8));
+    assert_eq!(Ok(0), gs1_check_digit(&[]));
+}
+
+/// Checks overflowing digits and check digits are rejected
+#[test]
+fn gs1_check_overflow() {
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 1,
+            found: 10
+        }),
+        gs1_check_digit(&[0, 10, 1])
+    );
+    assert_eq!(
+        Err(UpcError::CheckDigitOverflow { found: -1 }),
+        gs1_check(&[0, 1, 2], -1)
+    );
+}
+
+/// Checks long keys don't overflow the weighted sum
+#[test]
+fn gs1_check_digit_long() {
+    let my_digits = vec![9; 10_001];
+
+    assert_eq!(Ok(3), gs1_check_digit(&my_digits));
+}
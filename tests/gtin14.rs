@@ -0,0 +1,63 @@
+use upc_checker::{Standard, Upc};
+
+/// Checks if check_upc is returning the right values for
+/// [GTIN-14](https://en.wikipedia.org/wiki/Global_Trade_Item_Number)
+#[test]
+fn valid_gtin14() {
+    let my_upc = Standard::Gtin14([1, 0, 6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 3]);
+    let my_check_code: i8 = 3;
+
+    let my_upc_struct = Upc {
+        upc: my_upc,
+        check_digit: my_check_code,
+    };
+
+    assert_eq!(Ok(true), my_upc_struct.check());
+}
+
+/// Checks that every standard normalizes to the same zero-padded GTIN-14
+#[test]
+fn normalize_to_gtin14() {
+    let expected = Upc {
+        upc: Standard::Gtin14([0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 5, 2, 6]),
+        check_digit: 4,
+    };
+
+    let codes = [
+        Standard::UpcA([0, 4, 2, 1, 0, 0, 0, 0, 5, 2, 6]),
+        Standard::UpcE([0, 4, 2, 5, 2, 6, 1]),
+        Standard::Ean13([0, 0, 4, 2, 1, 0, 0, 0, 0, 5, 2, 6]),
+        Standard::Gtin14([0, 0, 0, 4, 2, 1, 0, 0, 0, 0, 5, 2, 6]),
+    ];
+
+    for code in codes.iter() {
+        let my_upc_struct = Upc::new(code.clone()).unwrap();
+
+        assert_eq!(expected, my_upc_struct.to_gtin14());
+    }
+
+    let my_ean8 = Upc::new(Standard::Ean8([9, 6, 3, 8, 5, 0, 7])).unwrap();
+
+    assert_eq!(
+        Standard::Gtin14([0, 0, 0, 0, 0, 0, 9, 6, 3, 8, 5, 0, 7]),
+        my_ean8.to_gtin14().upc
+    );
+    assert_eq!(Ok(true), my_ean8.to_gtin14().check());
+}
+
+/// Checks that a packaging indicator yields a valid case-level GTIN-14 with
+/// a recomputed check digit
+#[test]
+fn gtin14_packaging_indicator() {
+    let my_upc_struct = Upc::new(Standard::UpcA([6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 3])).unwrap();
+    let my_case = my_upc_struct.with_packaging_indicator(1).unwrap();
+
+    assert_eq!(
+        Upc {
+            upc: Standard::Gtin14([1, 0, 6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 3]),
+            check_digit: 3,
+        },
+        my_case
+    );
+    assert_eq!(None, my_case.to_ean13());
+}
@@ -0,0 +1,62 @@
+use std::convert::TryFrom;
+use upc_checker::{Isbn10, Isbn13, Upc, UpcError};
+
+/// Checks ISBN-10 validation, including the `X` check digit
+#[test]
+fn valid_isbn10() {
+    for input in ["0-306-40615-2", "0131103628", "0-8044-2957-X", "080442957x"].iter() {
+        let my_isbn: Isbn10 = input.parse().unwrap();
+
+        assert_eq!(Ok(true), my_isbn.check(), "{}", input);
+    }
+
+    let my_isbn: Isbn10 = "0-8044-2957-X".parse().unwrap();
+
+    assert_eq!(10, my_isbn.check_digit);
+    assert_eq!("080442957X", my_isbn.to_string());
+    assert_eq!(Ok(false), "0306406153".parse::<Isbn10>().unwrap().check());
+}
+
+/// Checks that ISBN-13s must be Bookland EANs with a valid check digit
+#[test]
+fn valid_isbn13() {
+    let my_isbn: Isbn13 = "978-3-16-148410-0".parse().unwrap();
+    assert_eq!(Ok(true), my_isbn.check());
+
+    let my_isbn: Isbn13 = "979-10-90636-07-1".parse().unwrap();
+    assert_eq!(Ok(true), my_isbn.check());
+
+    assert_eq!(
+        Err(UpcError::InvalidPrefix),
+        "4006381333931".parse::<Isbn13>()
+    );
+}
+
+/// Checks conversion between ISBN-10 and ISBN-13 in both directions
+#[test]
+fn isbn_conversion() {
+    let my_isbn10: Isbn10 = "0-306-40615-2".parse().unwrap();
+    let my_isbn13: Isbn13 = "978-0-306-40615-7".parse().unwrap();
+
+    assert_eq!(Ok(my_isbn13.clone()), my_isbn10.to_isbn13());
+    assert_eq!(Ok(my_isbn10), my_isbn13.to_isbn10());
+
+    let my_isbn13: Isbn13 = "979-10-90636-07-1".parse().unwrap();
+    assert_eq!(Err(UpcError::InvalidPrefix), my_isbn13.to_isbn10());
+}
+
+/// Checks that scanned Bookland EANs convert to and from [Isbn13]
+#[test]
+fn isbn13_upc() {
+    let my_upc_struct: Upc = "9781593278281".parse().unwrap();
+    let my_isbn = Isbn13::try_from(my_upc_struct.clone()).unwrap();
+
+    assert_eq!("1593278284", my_isbn.to_isbn10().unwrap().to_string());
+    assert_eq!(my_upc_struct, Upc::from(my_isbn));
+
+    let my_upc_struct: Upc = "036000241457".parse().unwrap();
+    assert_eq!(
+        Err(UpcError::InvalidPrefix),
+        Isbn13::try_from(my_upc_struct)
+    );
+}
@@ -0,0 +1,76 @@
+use upc_checker::{Isbn10, Isbn13, UpcError};
+
+/// Checks that ISBN-13s are hyphenated at their group, registrant and
+/// publication boundaries across a range of registration groups
+#[test]
+fn hyphenate_isbn13() {
+    let cases = [
+        "978-0-306-40615-7",
+        "978-0-13-110362-7",
+        "978-1-59327-828-1",
+        "978-3-16-148410-0",
+        "979-10-90636-07-1",
+    ];
+
+    for hyphenated in cases.iter() {
+        let my_isbn: Isbn13 = hyphenated.parse().unwrap();
+
+        assert_eq!(*hyphenated, my_isbn.hyphenated().unwrap().to_string());
+    }
+}
+
+/// Checks that narrower sub-ranges within a registration group are given
+/// their own registrant lengths
+#[test]
+fn hyphenate_isbn13_sub_ranges() {
+    let my_isbn = Isbn13::new([9, 7, 8, 0, 2, 2, 8, 0, 1, 2, 3, 4]).unwrap();
+    assert_eq!("978-0-2280-1234-4", my_isbn.hyphenated().unwrap().to_string());
+
+    let cases = [
+        "978-1-7320000-1-8",
+        "978-2-4960-1234-7",
+        "978-7-5000-1234-4",
+        "978-80-7000-123-3",
+        "978-91-7000-123-9",
+    ];
+
+    for hyphenated in cases.iter() {
+        let my_isbn: Isbn13 = hyphenated.parse().unwrap();
+
+        assert_eq!(*hyphenated, my_isbn.hyphenated().unwrap().to_string());
+    }
+}
+
+/// Checks that ISBN-10s are hyphenated without their prefix, keeping an `X`
+/// check digit
+#[test]
+fn hyphenate_isbn10() {
+    let my_isbn: Isbn10 = "080442957X".parse().unwrap();
+    let my_hyphenated = my_isbn.hyphenated().unwrap();
+
+    assert_eq!("0-8044-2957-X", my_hyphenated.to_string());
+    assert_eq!(None, my_hyphenated.prefix());
+    assert_eq!(&[0], my_hyphenated.group());
+    assert_eq!(&[8, 0, 4, 4], my_hyphenated.registrant());
+    assert_eq!(&[2, 9, 5, 7], my_hyphenated.publication());
+    assert_eq!(10, my_hyphenated.check_digit());
+}
+
+/// Checks that ISBNs outside of the range table can't be hyphenated
+#[test]
+fn hyphenate_unknown_range() {
+    // 978-6 has a 3 digit group, where 978-66x is unassigned
+    let my_isbn = Isbn13::new([9, 7, 8, 6, 6, 0, 1, 2, 3, 4, 5, 6]).unwrap();
+
+    assert_eq!(Err(UpcError::UnknownRange), my_isbn.hyphenated());
+}
+
+/// Checks that ISBNs in an unassigned registration group give an error
+/// rather than a guessed hyphenation
+#[test]
+fn hyphenate_unknown_group() {
+    // 979-0 is used by ISMNs and 979-13 to 979-79 aren't assigned yet
+    let my_isbn: Isbn13 = "9795000000006".parse().unwrap();
+
+    assert_eq!(Err(UpcError::UnknownRange), my_isbn.hyphenated());
+}
@@ -0,0 +1,59 @@
+use std::convert::TryFrom;
+use upc_checker::{Ismn, Issn, Upc, UpcError};
+
+/// Checks ISSN validation, including the `X` check digit
+#[test]
+fn valid_issn() {
+    for input in ["0317-8471", "0028-0836", "1050-124X", "2049 3630"].iter() {
+        let my_issn: Issn = input.parse().unwrap();
+
+        assert_eq!(Ok(true), my_issn.check(), "{}", input);
+    }
+
+    let my_issn: Issn = "1050124x".parse().unwrap();
+    assert_eq!("1050124X", my_issn.to_string());
+    assert_eq!("1050-124X", format!("{:#}", my_issn));
+
+    assert_eq!(Ok(false), "0317-8472".parse::<Issn>().unwrap().check());
+    assert_eq!(
+        Err(UpcError::InvalidLength(9)),
+        "0317-84712".parse::<Issn>()
+    );
+}
+
+/// Checks that an ISSN is embedded into its 977-prefixed EAN-13 with its
+/// variant digits and extracted back out again
+#[test]
+fn issn_ean13() {
+    let my_issn: Issn = "1050-124X".parse().unwrap();
+    let my_ean = my_issn.to_ean13([0, 3]).unwrap();
+
+    assert_eq!("9771050124039", my_ean.to_string());
+    assert_eq!(Ok(true), my_ean.check());
+    assert_eq!(Ok((my_issn, [0, 3])), Issn::from_ean13(&my_ean));
+
+    let my_upc_struct: Upc = "9780306406157".parse().unwrap();
+    assert_eq!(
+        Err(UpcError::InvalidPrefix),
+        Issn::from_ean13(&my_upc_struct)
+    );
+}
+
+/// Checks that ISMNs are parsed from both their EAN-13 and legacy forms
+#[test]
+fn valid_ismn() {
+    let my_ismn: Ismn = "979-0-2306-7118-7".parse().unwrap();
+
+    assert_eq!(Ok(true), my_ismn.check());
+    assert_eq!(Ok(my_ismn.clone()), "M-2306-7118-7".parse());
+    assert_eq!(Ok(my_ismn.clone()), Ismn::new([2, 3, 0, 6, 7, 1, 1, 8]));
+    assert_eq!("M230671187", format!("{:#}", my_ismn));
+
+    let my_upc_struct = Upc::from(my_ismn.clone());
+    assert_eq!(Ok(my_ismn), Ismn::try_from(my_upc_struct));
+
+    assert_eq!(
+        Err(UpcError::InvalidPrefix),
+        "9780306406157".parse::<Ismn>()
+    );
+}
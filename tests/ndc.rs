@@ -0,0 +1,158 @@
+use upc_checker::{Ndc, NdcFormat, Standard, Upc, UpcError};
+
+/// Checks the NDC is extracted from a number system 3 UPC-A
+#[test]
+fn ndc_from_upc() {
+    let my_upc: Upc = "3 00028 03101 8".parse().unwrap();
+    let my_ndc = Ndc::from_upc(&my_upc, NdcFormat::FourFourTwo).unwrap();
+
+    assert_eq!([0, 0, 0, 2, 8, 0, 3, 1, 0, 1], my_ndc.ndc);
+    assert_eq!(&[0, 0, 0, 2], my_ndc.labeler());
+    assert_eq!(&[8, 0, 3, 1], my_ndc.product());
+    assert_eq!(&[0, 1], my_ndc.package());
+}
+
+/// Checks codes without number system 3 have no NDC
+#[test]
+fn ndc_from_upc_invalid_prefix() {
+    let my_upc: Upc = "036000291452".parse().unwrap();
+
+    assert_eq!(
+        Err(UpcError::InvalidPrefix),
+        Ndc::from_upc(&my_upc, NdcFormat::FiveThreeTwo)
+    );
+}
+
+/// Checks codes with overflowing digits are rejected rather than extracted
+#[test]
+fn ndc_from_upc_overflow() {
+    let my_upc = Upc {
+        upc: Standard::UpcA([3, -5, 0, 9, 0, 3, 0, 0, 1, 1, 1]),
+        check_digit: 7,
+    };
+
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 1,
+            found: -5
+        }),
+        Ndc::from_upc(&my_upc, NdcFormat::FiveThreeTwo)
+    );
+}
+
+/// Checks a UPC-A is generated from an NDC with its check digit
+#[test]
+fn ndc_to_upc() {
+    let my_ndc: Ndc = "50903-001-11".parse().unwrap();
+    let my_upc = my_ndc.to_upc().unwrap();
+
+    assert_eq!("350903001117", my_upc.to_string());
+    assert_eq!(Ok(true), my_upc.check());
+}
+
+/// Checks each format is padded to the 5-4-2 HIPAA form
+#[test]
+fn ndc_to_hipaa() {
+    let my_cases = [
+        ("0002-8031-01", [0, 0, 0, 0, 2, 8, 0, 3, 1, 0, 1]),
+        ("50903-001-11", [5, 0, 9, 0, 3, 0, 0, 0, 1, 1, 1]),
+        ("12345-6789-0", [1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0]),
+    ];
+
+    for (my_string, my_hipaa) in my_cases.iter() {
+        let my_ndc: Ndc = my_string.parse().unwrap();
+
+        assert_eq!(*my_hipaa, my_ndc.to_hipaa(), "{}", my_string);
+        assert_eq!(
+            Ok(my_ndc.clone()),
+            Ndc::from_hipaa(*my_hipaa, my_ndc.format),
+            "{}",
+            my_string
+        );
+    }
+}
+
+/// Checks a HIPAA NDC whose padded segment doesn't start with zero is
+/// rejected
+#[test]
+fn ndc_from_hipaa_mismatch() {
+    let my_hipaa = [5, 0, 9, 0, 3, 1, 0, 0, 1, 1, 1];
+
+    assert_eq!(
+        Err(UpcError::InvalidNdcFormat),
+        Ndc::from_hipaa(my_hipaa, NdcFormat::FiveThreeTwo)
+    );
+    assert_eq!(
+        Err(UpcError::InvalidNdcFormat),
+        Ndc::from_hipaa(my_hipaa, NdcFormat::FourFourTwo)
+    );
+}
+
+/// Checks an NDC is converted between formats through its HIPAA form
+#[test]
+fn ndc_to_format() {
+    let my_ndc: Ndc = "01234-0567-8".parse().unwrap();
+
+    assert_eq!(
+        "1234-0567-08",
+        format!("{:#}", my_ndc.to_format(NdcFormat::FourFourTwo).unwrap())
+    );
+    assert_eq!(
+        "01234-567-08",
+        format!("{:#}", my_ndc.to_format(NdcFormat::FiveThreeTwo).unwrap())
+    );
+}
+
+/// Checks an NDC which doesn't fit another format isn't converted to it
+#[test]
+fn ndc_to_format_mismatch() {
+    let my_ndc: Ndc = "12345-6789-0".parse().unwrap();
+
+    assert_eq!(
+        Err(UpcError::InvalidNdcFormat),
+        my_ndc.to_format(NdcFormat::FourFourTwo)
+    );
+}
+
+/// Checks the format is detected from the hyphen positions
+#[test]
+fn ndc_parse_formats() {
+    let my_cases = [
+        ("0002-8031-01", NdcFormat::FourFourTwo),
+        ("50903-001-11", NdcFormat::FiveThreeTwo),
+        ("12345-6789-0", NdcFormat::FiveFourOne),
+    ];
+
+    for (my_string, my_format) in my_cases.iter() {
+        let my_ndc: Ndc = my_string.parse().unwrap();
+
+        assert_eq!(*my_format, my_ndc.format, "{}", my_string);
+        assert_eq!(*my_string, format!("{:#}", my_ndc));
+    }
+}
+
+/// Checks NDCs without a known segmentation are rejected
+#[test]
+fn ndc_parse_invalid() {
+    assert_eq!(Err(UpcError::InvalidNdcFormat), "5090300111".parse::<Ndc>());
+    assert_eq!(
+        Err(UpcError::InvalidNdcFormat),
+        "509-0300-111".parse::<Ndc>()
+    );
+    assert_eq!(
+        Err(UpcError::InvalidNdcFormat),
+        "5090-3-001-11".parse::<Ndc>()
+    );
+    assert_eq!(
+        Err(UpcError::InvalidLength(9)),
+        "50903-001-1".parse::<Ndc>()
+    );
+}
+
+/// Checks the plain display has no hyphens
+#[test]
+fn ndc_display() {
+    let my_ndc: Ndc = "50903-001-11".parse().unwrap();
+
+    assert_eq!("5090300111", my_ndc.to_string());
+}
@@ -13,7 +13,7 @@ fn overflow_check_digit() {
     };
 
     assert_eq!(
-        Err(UpcError::CheckDigitOverflow),
+        Err(UpcError::CheckDigitOverflow { found: 70 }),
         my_upc_struct.check()
     );
 }
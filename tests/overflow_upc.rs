@@ -1,6 +1,7 @@
 use upc_checker::{Standard, Upc, UpcError};
 
-/// Checks that `UpcError` is being properly called when is_1_digit = false for
+/// Checks that `UpcError::UpcOverflow` is being properly called with the
+/// offending position when is_1_digit = false for
 /// [UPC-A](https://en.wikipedia.org/wiki/Universal_Product_Code#Encoding)
 #[test]
 fn overflow_upc_a() {
@@ -12,5 +13,25 @@ fn overflow_upc_a() {
         check_digit: my_check_code,
     };
 
-    assert_eq!(Err(UpcError::CheckDigitOverflow), my_upc_struct.check());
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 5,
+            found: 12
+        }),
+        my_upc_struct.check()
+    );
+}
+
+/// Checks that errors are displayed with the offending position and value
+#[test]
+fn overflow_upc_display() {
+    let my_error = UpcError::UpcOverflow {
+        position: 5,
+        found: 12,
+    };
+
+    assert_eq!(
+        "digit at position 5 is 12, expected 0-9",
+        my_error.to_string()
+    );
 }
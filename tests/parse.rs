@@ -0,0 +1,80 @@
+use std::convert::TryFrom;
+use upc_checker::{Standard, Upc, UpcError};
+
+/// Checks that printed codes are parsed with the [Standard] detected from
+/// their length
+#[test]
+fn parse_standards() {
+    let cases = [
+        (
+            "036000241457",
+            Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 4, 1, 4, 5]),
+            7,
+        ),
+        (
+            "4006381333931",
+            Standard::Ean13([4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3]),
+            1,
+        ),
+        ("96385074", Standard::Ean8([9, 6, 3, 8, 5, 0, 7]), 4),
+        (
+            "10614141000033",
+            Standard::Gtin14([1, 0, 6, 1, 4, 1, 4, 1, 0, 0, 0, 0, 3]),
+            3,
+        ),
+    ];
+
+    for (input, upc, check_digit) in cases.iter() {
+        let my_upc_struct: Upc = input.parse().unwrap();
+
+        assert_eq!(upc, &my_upc_struct.upc);
+        assert_eq!(*check_digit, my_upc_struct.check_digit);
+        assert_eq!(Ok(true), my_upc_struct.check());
+    }
+}
+
+/// Checks that label formatting such as whitespace, spaces and hyphens is
+/// tolerated
+#[test]
+fn parse_label_formatting() {
+    let expected = Upc::try_from("036000241457").unwrap();
+
+    for input in ["  036000241457\n", "0 36000 24145 7", "0-36000-24145-7"].iter() {
+        assert_eq!(Ok(expected.clone()), Upc::try_from(*input));
+    }
+}
+
+/// Checks that bad characters and lengths give a parse error instead of a
+/// panic
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        Err(UpcError::InvalidCharacter {
+            position: 4,
+            found: 'O'
+        }),
+        "0360O0241457".parse::<Upc>()
+    );
+    assert_eq!(
+        Err(UpcError::InvalidLength(11)),
+        "03600024145".parse::<Upc>()
+    );
+    assert_eq!(Err(UpcError::InvalidLength(0)), "".parse::<Upc>());
+    assert_eq!(
+        Err(UpcError::InvalidLength(20)),
+        "01234567890123456789".parse::<Upc>()
+    );
+}
+
+/// Checks that UPC-E codes can be parsed explicitly
+#[test]
+fn parse_upc_e() {
+    let my_upc_struct = Upc::parse_upc_e("04252614").unwrap();
+
+    assert_eq!(Standard::UpcE([0, 4, 2, 5, 2, 6, 1]), my_upc_struct.upc);
+    assert_eq!(Ok(true), my_upc_struct.check());
+    assert_eq!(
+        Err(UpcError::InvalidLength(12)),
+        Upc::parse_upc_e("036000241457")
+    );
+}
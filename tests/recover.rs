@@ -0,0 +1,69 @@
+use upc_checker::{Upc, UpcError};
+
+/// Checks that a single unknown digit is recovered in every position of
+/// every standard, including the check digit
+#[test]
+fn recover_every_position() {
+    for code in [
+        "036000241457",
+        "4006381333931",
+        "96385074",
+        "10614141000033",
+    ]
+    .iter()
+    {
+        for position in 0..code.len() {
+            let mut damaged = code.to_string();
+            damaged.replace_range(position..position + 1, "?");
+
+            assert_eq!(
+                code.to_string(),
+                Upc::recover(&damaged).unwrap().to_string()
+            );
+        }
+    }
+}
+
+/// Checks that a torn label with spacing is recovered
+#[test]
+fn recover_label() {
+    let my_upc_struct = Upc::recover(" 0 36000 ?4145 7 ").unwrap();
+
+    assert_eq!("036000241457", my_upc_struct.to_string());
+    assert_eq!(Ok(true), my_upc_struct.check());
+}
+
+/// Checks that UPC-E codes are recovered, including when their last digit
+/// has more than one or no valid solution
+#[test]
+fn recover_upc_e() {
+    assert_eq!(
+        "04252614",
+        Upc::recover_upc_e("04252?14").unwrap().to_string()
+    );
+    assert_eq!(
+        "04252614",
+        Upc::recover_upc_e("?4252614").unwrap().to_string()
+    );
+    assert_eq!(
+        Err(UpcError::AmbiguousDigit { position: 6 }),
+        Upc::recover_upc_e("042526?5")
+    );
+    assert_eq!(
+        Err(UpcError::UnrecoverableDigit { position: 6 }),
+        Upc::recover_upc_e("042526?7")
+    );
+}
+
+/// Checks that anything but exactly one unknown digit is refused
+#[test]
+fn recover_unknown_count() {
+    assert_eq!(
+        Err(UpcError::UnknownDigits(0)),
+        Upc::recover("036000241457")
+    );
+    assert_eq!(
+        Err(UpcError::UnknownDigits(2)),
+        Upc::recover("03600?24?457")
+    );
+}
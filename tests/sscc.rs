@@ -0,0 +1,134 @@
+use upc_checker::{Sscc, SsccAllocator, UpcError};
+
+/// Checks a valid SSCC from the GS1 General Specifications passes
+#[test]
+fn sscc_valid() {
+    let my_sscc = Sscc {
+        sscc: [1, 0, 6, 1, 4, 1, 4, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        check_digit: 7,
+    };
+
+    assert_eq!(Ok(true), my_sscc.check());
+    assert_eq!(1, my_sscc.extension());
+}
+
+/// Checks an SSCC with the wrong check digit fails
+#[test]
+fn sscc_invalid() {
+    let my_sscc = Sscc {
+        sscc: [1, 0, 6, 1, 4, 1, 4, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        check_digit: 6,
+    };
+
+    assert_eq!(Ok(false), my_sscc.check());
+}
+
+/// Checks overflowing digits are rejected
+#[test]
+fn sscc_overflow() {
+    let my_sscc = Sscc {
+        sscc: [1, 0, 6, 1, 4, 1, 4, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        check_digit: 10,
+    };
+
+    assert_eq!(
+        Err(UpcError::CheckDigitOverflow { found: 10 }),
+        my_sscc.check()
+    );
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 0,
+            found: 10
+        }),
+        Sscc::from_parts(10, &[0, 6, 1, 4, 1, 4, 1], 0)
+    );
+}
+
+/// Checks an SSCC is generated from its parts with its check digit
+#[test]
+fn sscc_from_parts() {
+    let my_sscc = Sscc::from_parts(1, &[0, 6, 1, 4, 1, 4, 1], 123_456_789).unwrap();
+
+    assert_eq!(
+        [1, 0, 6, 1, 4, 1, 4, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        my_sscc.sscc
+    );
+    assert_eq!(7, my_sscc.check_digit);
+}
+
+/// Checks invalid company prefixes and serial references are rejected
+#[test]
+fn sscc_from_parts_invalid() {
+    assert_eq!(
+        Err(UpcError::InvalidPrefixLength(13)),
+        Sscc::from_parts(0, &[0; 13], 0)
+    );
+    assert_eq!(
+        Err(UpcError::ValueTooLarge(1_000_000_000)),
+        Sscc::from_parts(0, &[0, 6, 1, 4, 1, 4, 1], 1_000_000_000)
+    );
+}
+
+/// Checks SSCCs are parsed with or without the application identifier
+#[test]
+fn sscc_parse() {
+    let my_expected = Sscc::from_parts(1, &[0, 6, 1, 4, 1, 4, 1], 123_456_789).unwrap();
+
+    assert_eq!(Ok(my_expected.clone()), "106141411234567897".parse());
+    assert_eq!(Ok(my_expected.clone()), "(00)106141411234567897".parse());
+    assert_eq!(Ok(my_expected), " (00) 1 0614141 123456789 7 ".parse());
+    assert_eq!(
+        Err(UpcError::InvalidLength(17)),
+        "(00)10614141123456789".parse::<Sscc>()
+    );
+    assert_eq!(
+        Err(UpcError::InvalidLength(20)),
+        "00106141411234567897".parse::<Sscc>()
+    );
+}
+
+/// Checks SSCCs are formatted with and without the application identifier
+#[test]
+fn sscc_display() {
+    let my_sscc: Sscc = "106141411234567897".parse().unwrap();
+
+    assert_eq!("106141411234567897", my_sscc.to_string());
+    assert_eq!("(00)106141411234567897", format!("{:#}", my_sscc));
+}
+
+/// Checks consecutive serial references are allocated with check digits
+#[test]
+fn sscc_allocator() {
+    let my_allocator = SsccAllocator::new(0, &[0, 6, 1, 4, 1, 4, 1], 0).unwrap();
+    let my_ssccs: Vec<String> = my_allocator.take(2).map(|x| x.to_string()).collect();
+
+    assert_eq!(vec!["006141410000000005", "006141410000000012"], my_ssccs);
+}
+
+/// Checks allocation stops once the serial references run out
+#[test]
+fn sscc_allocator_exhausted() {
+    let mut my_allocator =
+        SsccAllocator::new(3, &[5, 0, 1, 2, 3, 4, 5, 9, 9, 9, 9, 9], 9998).unwrap();
+
+    assert_eq!(2, my_allocator.remaining());
+    assert_eq!(
+        "350123459999999981",
+        my_allocator.next_sscc().unwrap().to_string()
+    );
+    assert_eq!(
+        "350123459999999998",
+        my_allocator.next_sscc().unwrap().to_string()
+    );
+    assert_eq!(Err(UpcError::CapacityExhausted), my_allocator.next_sscc());
+    assert_eq!(None, my_allocator.next());
+}
+
+/// Checks the iterator's size hint matches the remaining capacity
+#[test]
+fn sscc_allocator_size_hint() {
+    let my_allocator = SsccAllocator::new(3, &[5, 0, 1, 2, 3, 4, 5, 9, 9, 9, 9, 9], 9990).unwrap();
+
+    assert_eq!((10, Some(10)), my_allocator.size_hint());
+    assert_eq!(10, my_allocator.count());
+}
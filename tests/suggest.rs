@@ -0,0 +1,109 @@
+use upc_checker::{Correction, Standard, Upc};
+
+/// Checks that a mistyped digit is suggested back along with every other
+/// valid correction
+#[test]
+fn suggest_substitution() {
+    let my_upc_struct: Upc = "036000291462".parse().unwrap();
+    let my_suggestions: Vec<_> = my_upc_struct.suggestions().collect();
+
+    assert!(my_suggestions.iter().any(|x| {
+        x.upc.to_string() == "036000291452"
+            && x.correction
+                == Correction::Substitution {
+                    position: 10,
+                    from: 6,
+                    to: 5,
+                }
+    }));
+
+    // one substitution per position, as weights 1 and 3 never collide mod 10
+    let substitutions = my_suggestions
+        .iter()
+        .filter(|x| matches!(x.correction, Correction::Substitution { .. }))
+        .count();
+    assert_eq!(12, substitutions);
+
+    for suggestion in my_suggestions.iter() {
+        assert_eq!(Ok(true), suggestion.upc.check());
+    }
+}
+
+/// Checks that substitutions between neighbouring keys are ranked ahead of
+/// the others, regardless of position
+#[test]
+fn suggest_substitution_ranking() {
+    let my_upc_struct: Upc = "036000291462".parse().unwrap();
+    let my_positions: Vec<_> = my_upc_struct
+        .suggestions()
+        .map(|x| match x.correction {
+            Correction::Substitution { position, .. } => position,
+            Correction::Transposition { position } => position,
+        })
+        .collect();
+
+    assert_eq!(vec![0, 2, 4, 6, 7, 8, 9, 10, 1, 3, 5, 11], my_positions);
+}
+
+/// Checks that transpositions are ranked between neighbouring-key
+/// substitutions and every other substitution
+#[test]
+fn suggest_transposition() {
+    let my_upc_struct: Upc = "036000421457".parse().unwrap();
+    let my_corrections: Vec<_> = my_upc_struct
+        .suggestions()
+        .map(|x| x.correction)
+        .take(6)
+        .collect();
+
+    assert_eq!(
+        vec![
+            Correction::Substitution {
+                position: 0,
+                from: 0,
+                to: 2
+            },
+            Correction::Substitution {
+                position: 4,
+                from: 0,
+                to: 2
+            },
+            Correction::Transposition { position: 0 },
+            Correction::Transposition { position: 6 },
+            Correction::Transposition { position: 8 },
+            Correction::Substitution {
+                position: 1,
+                from: 3,
+                to: 9
+            },
+        ],
+        my_corrections
+    );
+}
+
+/// Checks that codes with overflowing digits have no suggestions rather
+/// than panicking
+#[test]
+fn suggest_overflow() {
+    let my_upc_struct = Upc {
+        upc: Standard::UpcA([127, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]),
+        check_digit: 2,
+    };
+    let my_check_digit = Upc {
+        upc: Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]),
+        check_digit: -128,
+    };
+
+    assert_eq!(0, my_upc_struct.suggestions().count());
+    assert_eq!(0, my_check_digit.suggestions().count());
+}
+
+/// Checks that a valid code has no substitutions suggested
+#[test]
+fn suggest_valid() {
+    let my_upc_struct: Upc = "036000241457".parse().unwrap();
+
+    assert!(!my_upc_struct
+        .suggestions()
+        .any(|x| matches!(x.correction, Correction::Substitution { .. })));
+}
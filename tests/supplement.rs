@@ -0,0 +1,61 @@
+use upc_checker::{Barcode, Parity, Supplement, Upc, UpcError};
+
+use Parity::{Even as G, Odd as L};
+
+/// Checks the EAN-2 check value (the value modulo 4) and its parity pattern
+#[test]
+fn ean2_parity() {
+    let cases = [(12, [L, L]), (13, [L, G]), (34, [G, L]), (99, [G, G])];
+
+    for (value, pattern) in cases.iter() {
+        let my_supplement = Supplement::Ean2([value / 10, value % 10]);
+
+        assert_eq!(Ok(value % 4), my_supplement.check_value());
+        assert_eq!(Ok(&pattern[..]), my_supplement.parity_pattern());
+        assert_eq!(Ok(true), my_supplement.check(pattern));
+    }
+}
+
+/// Checks the EAN-5 check value (weighted 3, 9, 3, 9, 3) and its parity
+/// pattern
+#[test]
+fn ean5_parity() {
+    let my_supplement: Supplement = "52495".parse().unwrap();
+
+    assert_eq!(Ok(1), my_supplement.check_value());
+    assert_eq!(Ok(true), my_supplement.check(&[G, L, G, L, L]));
+    assert_eq!(Ok(false), my_supplement.check(&[L, L, G, L, G]));
+
+    let my_supplement: Supplement = "51299".parse().unwrap();
+    assert_eq!(Ok(&[L, G, L, L, G][..]), my_supplement.parity_pattern());
+
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 1,
+            found: 12
+        }),
+        Supplement::Ean5([5, 12, 9, 9, 0]).check_value()
+    );
+}
+
+/// Checks that codes with and without an add-on are parsed and displayed
+#[test]
+fn barcode_parse() {
+    let my_barcode: Barcode = "9780306406157 51299".parse().unwrap();
+
+    assert_eq!("9780306406157".parse::<Upc>().unwrap(), my_barcode.upc);
+    assert_eq!(
+        Some(Supplement::Ean5([5, 1, 2, 9, 9])),
+        my_barcode.supplement
+    );
+    assert_eq!(Ok(true), my_barcode.check());
+    assert_eq!("9780306406157 51299", my_barcode.to_string());
+
+    let my_barcode: Barcode = "0 36000 24145 7 12".parse().unwrap();
+    assert_eq!(Some(Supplement::Ean2([1, 2])), my_barcode.supplement);
+    assert_eq!("0 36000 24145 7 12", format!("{:#}", my_barcode));
+
+    let my_barcode: Barcode = "0 36000 24145 7".parse().unwrap();
+    assert_eq!(None, my_barcode.supplement);
+    assert_eq!("036000241457", my_barcode.to_string());
+}
@@ -0,0 +1,64 @@
+use upc_checker::{Standard, Upc, UpcError};
+
+/// Checks if check_upc is validating
+/// [UPC-E](https://en.wikipedia.org/wiki/Universal_Product_Code#UPC-E) through
+/// its expanded UPC-A form
+#[test]
+fn valid_upc_e() {
+    let my_upc = Standard::UpcE([0, 4, 2, 5, 2, 6, 1]);
+    let my_check_code: i8 = 4;
+
+    let my_upc_struct = Upc {
+        upc: my_upc,
+        check_digit: my_check_code,
+    };
+
+    assert_eq!(Ok(true), my_upc_struct.check());
+}
+
+/// Checks expansion and compression for every zero-suppression rule (last
+/// digit 0-2, 3, 4 and 5-9) across number systems 0 and 1
+#[test]
+fn upc_e_round_trip() {
+    let pairs = [
+        ([0, 4, 2, 5, 2, 6, 1], [0, 4, 2, 1, 0, 0, 0, 0, 5, 2, 6]),
+        ([1, 2, 3, 4, 5, 6, 0], [1, 2, 3, 0, 0, 0, 0, 0, 4, 5, 6]),
+        ([0, 6, 5, 4, 7, 8, 3], [0, 6, 5, 4, 0, 0, 0, 0, 0, 7, 8]),
+        ([1, 3, 3, 3, 3, 1, 4], [1, 3, 3, 3, 3, 0, 0, 0, 0, 0, 1]),
+        ([0, 1, 2, 3, 4, 5, 6], [0, 1, 2, 3, 4, 5, 0, 0, 0, 0, 6]),
+    ];
+
+    for (upc_e, upc_a) in pairs.iter() {
+        let my_upc_e = Upc::new(Standard::UpcE(*upc_e)).unwrap();
+        let my_upc_a = my_upc_e.to_upc_a().unwrap();
+
+        assert_eq!(Standard::UpcA(*upc_a), my_upc_a.upc);
+        assert_eq!(Ok(true), my_upc_a.check());
+        assert_eq!(Some(my_upc_e), my_upc_a.to_upc_e());
+    }
+}
+
+/// Checks that UPC-A codes without enough suppressible zeros, or outside of
+/// number systems 0 and 1, have no UPC-E form
+#[test]
+fn upc_a_not_compressible() {
+    let my_upc = Upc::new(Standard::UpcA([0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5])).unwrap();
+    assert_eq!(None, my_upc.to_upc_e());
+
+    let my_upc = Upc::new(Standard::UpcA([2, 1, 2, 3, 4, 5, 0, 0, 0, 0, 6])).unwrap();
+    assert_eq!(None, my_upc.to_upc_e());
+}
+
+/// Checks that UPC-E codes outside of number systems 0 and 1 are refused
+#[test]
+fn upc_e_invalid_number_system() {
+    let my_upc_struct = Upc {
+        upc: Standard::UpcE([2, 1, 2, 3, 4, 5, 6]),
+        check_digit: 5,
+    };
+
+    assert_eq!(
+        Err(UpcError::InvalidNumberSystem { found: 2 }),
+        my_upc_struct.check()
+    );
+}
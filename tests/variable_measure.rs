@@ -0,0 +1,95 @@
+use upc_checker::{
+    price_check_digit_4, price_check_digit_5, MeasureFormat, MeasureLayout, Standard, Upc,
+    UpcError, VariableMeasure,
+};
+
+/// Checks the GS1 price check digit examples for 4 and 5-digit prices
+#[test]
+fn price_check_digits() {
+    assert_eq!(Ok(9), price_check_digit_4([2, 8, 7, 5]));
+    assert_eq!(Ok(8), price_check_digit_4([0, 4, 9, 9]));
+    assert_eq!(Ok(6), price_check_digit_5([1, 4, 6, 8, 5]));
+    assert_eq!(Ok(1), price_check_digit_5([0, 1, 2, 3, 4]));
+    assert_eq!(
+        Err(UpcError::UpcOverflow {
+            position: 2,
+            found: 10
+        }),
+        price_check_digit_4([0, 4, 10, 9])
+    );
+}
+
+/// Checks that codes are generated and decoded again for every layout in
+/// both UPC-A and EAN-13
+#[test]
+fn variable_measure_round_trip() {
+    let cases = [
+        (MeasureFormat::UpcA, MeasureLayout::Unchecked, 12345, 1299),
+        (MeasureFormat::UpcA, MeasureLayout::Checked4, 12345, 499),
+        (MeasureFormat::Ean13(4), MeasureLayout::Checked5, 1234, 1234),
+        (
+            MeasureFormat::Ean13(8),
+            MeasureLayout::Unchecked,
+            54321,
+            99999,
+        ),
+    ];
+
+    for (format, layout, item, value) in cases.iter() {
+        let my_measure = VariableMeasure {
+            format: *format,
+            layout: *layout,
+            item: *item,
+            value: *value,
+        };
+        let my_upc_struct = my_measure.to_upc().unwrap();
+
+        assert_eq!(Ok(true), my_upc_struct.check());
+        assert_eq!(Ok(my_measure), my_upc_struct.variable_measure(*layout));
+    }
+}
+
+/// Checks that a deli label is split into item and price, rejecting a wrong
+/// price check digit
+#[test]
+fn variable_measure_decode() {
+    let my_upc_struct: Upc = "2 12345 8 0499 5".parse().unwrap();
+
+    assert_eq!(
+        Ok(VariableMeasure {
+            format: MeasureFormat::UpcA,
+            layout: MeasureLayout::Checked4,
+            item: 12345,
+            value: 499,
+        }),
+        my_upc_struct.variable_measure(MeasureLayout::Checked4)
+    );
+
+    let my_upc_struct = Upc::new(Standard::UpcA([2, 1, 2, 3, 4, 5, 7, 0, 4, 9, 9])).unwrap();
+    assert_eq!(
+        Err(UpcError::PriceCheckMismatch {
+            expected: 8,
+            found: 7
+        }),
+        my_upc_struct.variable_measure(MeasureLayout::Checked4)
+    );
+}
+
+/// Checks that only number system 2 and prefix 20-29 codes are decoded, and
+/// that oversized values are refused
+#[test]
+fn variable_measure_errors() {
+    let my_upc_struct: Upc = "036000241457".parse().unwrap();
+    assert_eq!(
+        Err(UpcError::InvalidPrefix),
+        my_upc_struct.variable_measure(MeasureLayout::Unchecked)
+    );
+
+    let my_measure = VariableMeasure {
+        format: MeasureFormat::UpcA,
+        layout: MeasureLayout::Checked4,
+        item: 1,
+        value: 10000,
+    };
+    assert_eq!(Err(UpcError::ValueTooLarge(10000)), my_measure.to_upc());
+}
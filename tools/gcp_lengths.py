@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+"""Regenerates the GS1 company prefix length table in src/company_prefix.rs.
+
+Download the latest gcpprefixformatlist.xml from
+https://www.gs1.org/standards/bc-epc-interop and run:
+
+    python3 tools/gcp_lengths.py gcpprefixformatlist.xml
+
+Everything between the generated markers in src/company_prefix.rs is
+replaced, with every <entry> copied over as-is.
+"""
+
+import pathlib
+import sys
+import xml.etree.ElementTree as ElementTree
+
+BEGIN = "// BEGIN GENERATED TABLE\n"
+END = "// END GENERATED TABLE\n"
+
+TARGET = pathlib.Path(__file__).resolve().parent.parent / "src" / "company_prefix.rs"
+
+
+def read_entries(root):
+    """Reads each (prefix, length) entry, ignoring any XML namespace."""
+    return sorted(
+        (entry.get("prefix"), int(entry.get("gcpLength")))
+        for entry in root.iter()
+        if entry.tag.split("}")[-1] == "entry"
+    )
+
+
+def render(entries):
+    """Renders the table in the style of the crate."""
+    lines = [
+        "// Generated by tools/gcp_lengths.py from gcpprefixformatlist.xml",
+        "static PREFIX_LENGTHS: &[PrefixLength] = &[",
+    ]
+    lines.extend(f'    prefix_length("{prefix}", {length}),' for prefix, length in entries)
+    lines.append("];")
+
+    return "\n".join(lines) + "\n"
+
+
+def main():
+    root = ElementTree.parse(sys.argv[1]).getroot()
+
+    source = TARGET.read_text()
+    head, rest = source.split(BEGIN)
+    _, tail = rest.split(END)
+
+    TARGET.write_text(head + BEGIN + render(read_entries(root)) + END + tail)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,85 @@
+#!/usr/bin/env python3
+"""Regenerates the ISBN range tables in src/isbn_ranges.rs.
+
+Download the latest RangeMessage.xml from
+https://www.isbn-international.org/range_file_generation and run:
+
+    python3 tools/isbn_ranges.py RangeMessage.xml
+
+Everything between the generated markers in src/isbn_ranges.rs is replaced,
+with every <EAN.UCC> prefix and registration <Group> copied over as-is.
+"""
+
+import pathlib
+import sys
+import xml.etree.ElementTree as ElementTree
+
+BEGIN = "// BEGIN GENERATED TABLES\n"
+END = "// END GENERATED TABLES\n"
+
+TARGET = pathlib.Path(__file__).resolve().parent.parent / "src" / "isbn_ranges.rs"
+
+
+def read_groups(root, path):
+    """Reads each (prefix, agency, [(range, length)]) under `path`."""
+    groups = []
+
+    for group in root.findall(path):
+        rules = [
+            (rule.findtext("Range"), int(rule.findtext("Length")))
+            for rule in group.findall("Rules/Rule")
+        ]
+        groups.append((group.findtext("Prefix"), group.findtext("Agency"), rules))
+
+    return groups
+
+
+def render_table(name, doc, groups):
+    """Renders a static table of groups in the style of the crate."""
+    lines = [f"/// {doc}", f"pub(crate) static {name}: &[Group] = &["]
+
+    for prefix, agency, rules in groups:
+        lines.append(f"    // {agency}")
+        lines.append("    Group {")
+        lines.append(f'        prefix: "{prefix}",')
+        lines.append("        rules: &[")
+        lines.extend(f'            rule("{range}", {length}),' for range, length in rules)
+        lines.append("        ],")
+        lines.append("    },")
+
+    lines.append("];")
+
+    return "\n".join(lines) + "\n"
+
+
+def render(prefixes, groups, serial):
+    """Renders both tables along with the serial number of the range file."""
+    return (
+        f"// Generated by tools/isbn_ranges.py from RangeMessage.xml {serial}\n"
+        "\n"
+        + render_table(
+            "PREFIXES",
+            "Registration group lengths for each EAN.UCC (Bookland) prefix",
+            prefixes,
+        )
+        + "\n"
+        + render_table("GROUPS", "Registrant lengths for each registration group", groups)
+    )
+
+
+def main():
+    root = ElementTree.parse(sys.argv[1]).getroot()
+
+    prefixes = read_groups(root, "EAN.UCCPrefixes/EAN.UCC")
+    groups = read_groups(root, "RegistrationGroups/Group")
+    serial = root.findtext("MessageSerialNumber") or ""
+
+    source = TARGET.read_text()
+    head, rest = source.split(BEGIN)
+    _, tail = rest.split(END)
+
+    TARGET.write_text(head + BEGIN + render(prefixes, groups, serial) + END + tail)
+
+
+if __name__ == "__main__":
+    main()